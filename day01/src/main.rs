@@ -1,15 +1,44 @@
 use anyhow::Result;
 use log::error;
-use std::collections::HashSet;
-use util::Input;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+use util::{timed, Input};
 
-const INPUT_FILE_PATH: &str = "./day01/input";
+const DAY: &str = "day01";
 
 /// Static list of spelled-out numbers.
 static SPELLED_OUT_NUMBERS: &[&str] = &[
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
+/// Static list of the zero digits of the scripts whose digits `filter_digits_unicode`
+/// recognizes. Each script holds the digits zero to nine in order. This is only a
+/// selection of the decimal digit ranges in Unicode, and digits of other scripts are
+/// not recognized.
+#[cfg(test)]
+static SUPPORTED_DIGIT_ZEROS: &[char] = &[
+    '0',        // ASCII
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{0A66}', // Gurmukhi
+    '\u{0AE6}', // Gujarati
+    '\u{0B66}', // Oriya
+    '\u{0BE6}', // Tamil
+    '\u{0C66}', // Telugu
+    '\u{0CE6}', // Kannada
+    '\u{0D66}', // Malayalam
+    '\u{0E50}', // Thai
+    '\u{0ED0}', // Lao
+    '\u{0F20}', // Tibetan
+    '\u{1040}', // Myanmar
+    '\u{17E0}', // Khmer
+    '\u{1810}', // Mongolian
+    '\u{FF10}', // Fullwidth
+];
+
 /// Static mapping of spelled-out numbers to their digit representations.
 static NUMBER_MAP: &[(&str, &str)] = &[
     ("zero", "0"),
@@ -24,14 +53,51 @@ static NUMBER_MAP: &[(&str, &str)] = &[
     ("nine", "9"),
 ];
 
+/// Lookup table from spelled-out numbers to their digit representations, built from `NUMBER_MAP`.
+static NUMBER_LOOKUP: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| NUMBER_MAP.iter().copied().collect());
+
+/// The distinct lengths of the spelled-out numbers, in increasing order.
+static SPELLED_OUT_LENGTHS: LazyLock<Vec<usize>> = LazyLock::new(|| {
+    let mut lengths: Vec<usize> = SPELLED_OUT_NUMBERS.iter().map(|word| word.len()).collect();
+    lengths.sort_unstable();
+    lengths.dedup();
+    lengths
+});
+
+/// Errors that can occur while calculating a calibration sum.
+#[derive(Debug, PartialEq, Eq)]
+enum CalibrationError {
+    /// The input contains no lines.
+    EmptyInput,
+    /// A line contains no digits, given by its one-based line number.
+    NoDigits { line_number: usize },
+    /// The sum of the calibration values does not fit in an `i32`.
+    Overflow,
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationError::EmptyInput => write!(f, "the input contains no lines"),
+            CalibrationError::NoDigits { line_number } => {
+                write!(f, "line {line_number} contains no digits")
+            }
+            CalibrationError::Overflow => write!(f, "calibration sum overflowed i32"),
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
 fn main() -> Result<()> {
-    let input = Input::load(INPUT_FILE_PATH)?;
+    let input = Input::for_day(DAY)?;
 
     println!("Part 1:");
-    println!("{}", part1(&input)?); // 53080
+    println!("{}", timed("part1", || part1(&input))?); // 53080
 
     println!("Part 2:");
-    println!("{}", part2(&input)?); // 53268
+    println!("{}", timed("part2", || part2(&input))?); // 53268
     Ok(())
 }
 
@@ -50,9 +116,7 @@ fn main() -> Result<()> {
 ///
 /// * `Result<i32>` - The sum of the numerical values after processing.
 fn part1(input: &Input) -> Result<i32> {
-    let digits_only = filter_digits_in_strings(input.lines());
-    let first_and_last = filter_first_and_last_strings(&digits_only);
-    Ok(sum_digits_in_strings(&first_and_last))
+    Ok(get_calibration_sum(input.lines())?)
 }
 
 /// Calculates a sum from strings containing spelled-out numbers in an `Input` instance.
@@ -72,9 +136,34 @@ fn part1(input: &Input) -> Result<i32> {
 /// * `Result<i32>` - The sum of the numerical values after processing.
 fn part2(input: &Input) -> Result<i32> {
     let no_spelled = replace_spelled_out_strings(input.lines());
-    let digits_only = filter_digits_in_strings(&no_spelled);
+    Ok(get_calibration_sum(&no_spelled)?)
+}
+
+/// Calculates the sum of the calibration values of every line in a collection of strings.
+///
+/// The calibration value of a line is the two-digit number formed by its first and
+/// last digits.
+///
+/// # Arguments
+///
+/// * `input` - An array of strings, each of which may contain any characters.
+///
+/// # Returns
+///
+/// * `Result<i32, CalibrationError>` - The sum of the calibration values, or an error
+///   naming the first line without digits, an empty input, or an overflowing sum.
+fn get_calibration_sum<T: AsRef<str>>(input: &[T]) -> Result<i32, CalibrationError> {
+    if input.is_empty() {
+        return Err(CalibrationError::EmptyInput);
+    }
+    let digits_only = filter_digits_in_strings(input);
     let first_and_last = filter_first_and_last_strings(&digits_only);
-    Ok(sum_digits_in_strings(&first_and_last))
+    if let Some(index) = first_and_last.iter().position(String::is_empty) {
+        return Err(CalibrationError::NoDigits {
+            line_number: index + 1,
+        });
+    }
+    sum_digits_in_strings(&first_and_last)
 }
 
 /// Extracts and returns all digits from a given string.
@@ -90,6 +179,33 @@ fn filter_digits(input: &str) -> String {
     input.chars().filter(|c| c.is_ascii_digit()).collect()
 }
 
+/// Extracts the digits of the supported scripts from a given string, normalized to ASCII.
+///
+/// Unlike `filter_digits`, digits from the scripts listed in `SUPPORTED_DIGIT_ZEROS`,
+/// such as the full-width `１`, are kept as well and converted to their ASCII
+/// counterparts. `char::to_digit` only recognizes ASCII digits, so the digits are
+/// looked up in that table, and digits of scripts missing from it are dropped.
+///
+/// # Arguments
+///
+/// * `input` - A string slice that may contain any characters.
+///
+/// # Returns
+///
+/// Returns a new `String` containing only the ASCII forms of the digits from the input string.
+#[cfg(test)]
+fn filter_digits_unicode(input: &str) -> String {
+    input
+        .chars()
+        .filter_map(|c| {
+            SUPPORTED_DIGIT_ZEROS.iter().find_map(|&zero| {
+                let value = (c as u32).checked_sub(zero as u32)?;
+                char::from_digit(value, 10)
+            })
+        })
+        .collect()
+}
+
 /// Returns a vector of strings that only contain digits.
 ///
 /// # Arguments
@@ -151,12 +267,15 @@ fn filter_first_and_last_strings<T: AsRef<str>>(input: &[T]) -> Vec<String> {
 ///
 /// # Returns
 ///
-/// Returns a new `i32` whose value is the sum of all digits.
-fn sum_digits_in_strings<T: AsRef<str>>(input: &[T]) -> i32 {
+/// * `Result<i32, CalibrationError>` - The sum of all numerical values, or
+///   `CalibrationError::Overflow` if the sum does not fit in an `i32`.
+fn sum_digits_in_strings<T: AsRef<str>>(input: &[T]) -> Result<i32, CalibrationError> {
     input
         .iter()
         .filter_map(|s| s.as_ref().parse::<i32>().ok())
-        .sum()
+        .try_fold(0i32, |sum, value| {
+            sum.checked_add(value).ok_or(CalibrationError::Overflow)
+        })
 }
 
 /// Returns a digit representation for a spelled-out number (zero to nine).
@@ -169,10 +288,7 @@ fn sum_digits_in_strings<T: AsRef<str>>(input: &[T]) -> i32 {
 ///
 /// An `Option` containing the digit as a string slice. Returns `None` if no match is found.
 fn get_digit_for_spelled_out_number(spelled_out: &str) -> Option<&'static str> {
-    NUMBER_MAP
-        .iter()
-        .find(|&&(word, _)| word == spelled_out)
-        .map(|&(_, digit)| digit)
+    NUMBER_LOOKUP.get(spelled_out).copied()
 }
 
 /// Finds a spelled-out number in a string starting from a specific index.
@@ -185,34 +301,22 @@ fn get_digit_for_spelled_out_number(spelled_out: &str) -> Option<&'static str> {
 /// # Returns
 ///
 /// An `Option` containing the spelled-out number as a string slice, starting from the given index.
+/// Returns `None` if the index is out of bounds or not on a character boundary.
 fn get_spelled_out_number(input: &str, index: usize) -> Option<&'static str> {
-    SPELLED_OUT_NUMBERS
-        .iter()
-        .find(|&&word| input[index..].starts_with(word))
-        .copied()
-}
-
-/// Identifies the start indexes of all spelled-out numbers in a string.
-///
-/// # Arguments
-///
-/// * `input` - The input string to search.
-///
-/// # Returns
-///
-/// A `Vec<usize>` containing the start indexes of spelled-out number found.
-fn get_spelled_out_number_indexes(input: &str) -> Vec<usize> {
-    let mut indexes = vec![];
-    for (index, _) in input.char_indices() {
-        if get_spelled_out_number(input, index).is_some() {
-            indexes.push(index)
-        }
-    }
-    indexes
+    let rest = input.get(index..)?;
+    SPELLED_OUT_LENGTHS.iter().find_map(|&length| {
+        let (&word, _) = NUMBER_LOOKUP.get_key_value(rest.get(..length)?)?;
+        Some(word)
+    })
 }
 
 /// Replaces spelled-out numbers in a string with their digit representations.
 ///
+/// The string is scanned one character at a time, and a digit is emitted whenever a
+/// spelled-out number starts at the current position. Spelled-out numbers may therefore
+/// overlap, so `"oneighthree"` becomes `"183"`. Characters that are not part of any
+/// spelled-out number are kept as they are.
+///
 /// # Arguments
 ///
 /// * `input` - The input string containing spelled-out numbers.
@@ -223,33 +327,94 @@ fn get_spelled_out_number_indexes(input: &str) -> Vec<usize> {
 /// Unmatched parts of the string are unchanged.
 fn replace_spelled_out(input: &str) -> String {
     let mut result = String::new();
-    let mut total_chars_to_skip = 0;
-    let number_indexes: HashSet<usize> =
-        get_spelled_out_number_indexes(input).into_iter().collect();
+    let mut covered_until = 0;
 
     for (index, ch) in input.char_indices() {
-        if number_indexes.contains(&index) {
-            if let Some(spelled_out) = get_spelled_out_number(input, index) {
-                if let Some(digit) = get_digit_for_spelled_out_number(spelled_out) {
-                    result.push_str(digit);
-                    total_chars_to_skip = spelled_out.len() - 1;
-                    continue;
-                } else {
-                    error!(
-                        "No digit representation found for spelled-out number {}",
-                        spelled_out
-                    )
-                }
+        if let Some(spelled_out) = get_spelled_out_number(input, index) {
+            if let Some(digit) = get_digit_for_spelled_out_number(spelled_out) {
+                result.push_str(digit);
+                covered_until = covered_until.max(index + spelled_out.len());
+                continue;
+            } else {
+                error!(
+                    "No digit representation found for spelled-out number {}",
+                    spelled_out
+                )
             }
-        } else if total_chars_to_skip == 0 {
+        }
+        if index >= covered_until {
             result.push(ch);
-        } else {
-            total_chars_to_skip -= 1;
         }
     }
     result
 }
 
+/// Returns the value of the digit or spelled-out number at the start of a string.
+///
+/// # Arguments
+///
+/// * `input` - A string slice that may start with a digit or a spelled-out number.
+///
+/// # Returns
+///
+/// An `Option` containing the value of the leading digit or spelled-out number.
+#[cfg(test)]
+fn leading_value(input: &str) -> Option<u32> {
+    input.chars().next()?.to_digit(10).or_else(|| {
+        SPELLED_OUT_NUMBERS
+            .iter()
+            .position(|word| input.starts_with(word))
+            .map(|value| value as u32)
+    })
+}
+
+/// Returns the value of the digit or spelled-out number at the end of a string.
+///
+/// # Arguments
+///
+/// * `input` - A string slice that may end with a digit or a spelled-out number.
+///
+/// # Returns
+///
+/// An `Option` containing the value of the trailing digit or spelled-out number.
+#[cfg(test)]
+fn trailing_value(input: &str) -> Option<u32> {
+    input.chars().last()?.to_digit(10).or_else(|| {
+        SPELLED_OUT_NUMBERS
+            .iter()
+            .position(|word| input.ends_with(word))
+            .map(|value| value as u32)
+    })
+}
+
+/// Finds the calibration value of a line without rewriting it first.
+///
+/// The first value is found by scanning forward for a digit or a spelled-out number,
+/// and the last value by scanning backward for a digit or a spelled-out number that
+/// ends at the current position. The two scans are independent, so overlapping
+/// spelled-out numbers such as `"eightwo"` need no special handling. This is only used
+/// to cross-check the replacing approach of `part2` in tests.
+///
+/// # Arguments
+///
+/// * `line` - A string slice that may contain digits and spelled-out numbers.
+///
+/// # Returns
+///
+/// An `Option` containing the two-digit number formed by the first and last values,
+/// or `None` if the line contains no digits or spelled-out numbers.
+#[cfg(test)]
+fn first_last_calibration_value(line: &str) -> Option<u32> {
+    let first = line
+        .char_indices()
+        .find_map(|(index, _)| leading_value(&line[index..]))?;
+    let last = line
+        .char_indices()
+        .rev()
+        .find_map(|(index, ch)| trailing_value(&line[..index + ch.len_utf8()]))?;
+    Some(first * 10 + last)
+}
+
 /// Replaces spelled-out numbers (zero to nine) in each string of an input collection.
 /// with their digit representations.
 ///
@@ -272,8 +437,8 @@ fn replace_spelled_out_strings<T: AsRef<str>>(input: &[T]) -> Vec<String> {
 mod test {
     use crate::{
         filter_digits, filter_digits_in_strings, filter_first_and_last_strings,
-        get_digit_for_spelled_out_number, get_spelled_out_number_indexes, part1, part2,
-        replace_spelled_out, replace_spelled_out_strings,
+        get_digit_for_spelled_out_number, part1, part2, replace_spelled_out,
+        replace_spelled_out_strings, NUMBER_MAP, SPELLED_OUT_NUMBERS,
     };
     use anyhow::Result;
     use util::Input;
@@ -318,6 +483,67 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    pub fn test_part2_line_without_digits() {
+        // Given an input whose second line contains neither digits nor spelled-out numbers
+        let input = Input::from_lines(&["two1nine", "pqrstu"]);
+
+        // When part2 is called
+        let actual = part2(&input);
+
+        // Then it should report the line, just like part1
+        assert_eq!(
+            actual.map_err(|error| error.to_string()),
+            Err("line 2 contains no digits".to_string()),
+            "part2 should report line 2 as containing no digits"
+        );
+    }
+
+    mod test_get_calibration_sum {
+        use crate::{get_calibration_sum, CalibrationError};
+
+        #[test]
+        pub fn sample_input() {
+            // Then it should return the calibration sum of the sample input
+            assert_eq!(
+                get_calibration_sum(&["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"]),
+                Ok(142),
+                "get_calibration_sum should return 142 for the sample input"
+            )
+        }
+
+        #[test]
+        pub fn line_without_digits() {
+            // Given an input whose second line contains no digits
+            let input = ["1abc2", "pqrstu", "treb7uchet"];
+
+            // When get_calibration_sum is called
+            let actual = get_calibration_sum(&input);
+
+            // Then it should return an error naming the offending line
+            assert_eq!(
+                actual,
+                Err(CalibrationError::NoDigits { line_number: 2 }),
+                "get_calibration_sum should report line 2 as containing no digits"
+            );
+            assert_eq!(
+                actual.unwrap_err().to_string(),
+                "line 2 contains no digits",
+                "the error message should name line 2"
+            );
+        }
+
+        #[test]
+        pub fn empty_input() {
+            // Then it should return an error for an input without lines
+            assert_eq!(
+                get_calibration_sum::<&str>(&[]),
+                Err(CalibrationError::EmptyInput),
+                "get_calibration_sum should return EmptyInput for an empty input"
+            )
+        }
+    }
+
     #[test]
     pub fn test_filter_digits() {
         // Given a string input with letters and digits
@@ -333,6 +559,40 @@ mod test {
         )
     }
 
+    mod test_filter_digits_unicode {
+        use crate::filter_digits_unicode;
+
+        #[test]
+        pub fn full_width_digits() {
+            // Then it should normalize full-width digits mixed with ASCII digits
+            assert_eq!(
+                filter_digits_unicode("１abc2ｄ３"),
+                "123",
+                "filter_digits_unicode should return \"123\" for an input of \"１abc2ｄ３\""
+            )
+        }
+
+        #[test]
+        pub fn other_scripts() {
+            // Then it should normalize digits from other scripts
+            assert_eq!(
+                filter_digits_unicode("x\u{0667}y\u{096F}"),
+                "79",
+                "filter_digits_unicode should return \"79\" for Arabic-Indic 7 and Devanagari 9"
+            )
+        }
+
+        #[test]
+        pub fn ascii_only() {
+            // Then it should behave like filter_digits for ASCII input
+            assert_eq!(
+                filter_digits_unicode("1abc2"),
+                "12",
+                "filter_digits_unicode should return \"12\" for an input of \"1abc2\""
+            )
+        }
+    }
+
     #[test]
     pub fn test_filter_digits_in_strings() {
         // Given an array of strings containing letters and digits
@@ -414,7 +674,7 @@ mod test {
     }
 
     mod test_sum_digits_in_strings {
-        use crate::sum_digits_in_strings;
+        use crate::{sum_digits_in_strings, CalibrationError};
 
         #[test]
         pub fn positive_numbers() {
@@ -425,7 +685,7 @@ mod test {
                 .collect();
 
             // When sum_digits_in_strings is called
-            let actual: i32 = sum_digits_in_strings(&input);
+            let actual: i32 = sum_digits_in_strings(&input).unwrap();
 
             // Then it should return the sum of the digits in all the strings
             assert_eq!(
@@ -444,7 +704,7 @@ mod test {
                 .collect();
 
             // When sum_digits_in_strings is called
-            let actual: i32 = sum_digits_in_strings(&input);
+            let actual: i32 = sum_digits_in_strings(&input).unwrap();
 
             // Then it should return the sum of the digits in all the strings
             assert_eq!(
@@ -463,7 +723,7 @@ mod test {
                 .collect();
 
             // When sum_digits_in_strings is called
-            let actual: i32 = sum_digits_in_strings(&input);
+            let actual: i32 = sum_digits_in_strings(&input).unwrap();
 
             // Then it should return the sum of the digits in all the strings
             assert_eq!(
@@ -472,6 +732,26 @@ mod test {
                        for an input of [\"12\", \"-38\", \"15\", \"77\"]"
             )
         }
+
+        #[test]
+        pub fn overflowing_sum() {
+            // Given a vector of strings whose sum exceeds i32::MAX
+            let input: Vec<String> = vec!["2147483647", "1"]
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect();
+
+            // When sum_digits_in_strings is called
+            let actual = sum_digits_in_strings(&input);
+
+            // Then it should return an overflow error instead of a wrapped sum
+            assert_eq!(
+                actual,
+                Err(CalibrationError::Overflow),
+                "sum_digits_in_strings should return CalibrationError::Overflow \
+                       for an input of [\"2147483647\", \"1\"]"
+            )
+        }
     }
 
     #[test]
@@ -480,7 +760,7 @@ mod test {
         let input = "eight";
 
         // When convert_to_digits is called
-        let actual = get_digit_for_spelled_out_number(&input).unwrap();
+        let actual = get_digit_for_spelled_out_number(input).unwrap();
 
         // Then it should return the spelled out number in digits
         assert_eq!(
@@ -489,6 +769,25 @@ mod test {
         )
     }
 
+    #[test]
+    pub fn test_get_digit_for_spelled_out_number_matches_linear_lookup() {
+        // Given every spelled-out number and a word that is not a number
+        let words = SPELLED_OUT_NUMBERS.iter().copied().chain(["eleven"]);
+
+        // Then the map-based lookup should return the same digit as a linear scan
+        for word in words {
+            let linear = NUMBER_MAP
+                .iter()
+                .find(|&&(spelled_out, _)| spelled_out == word)
+                .map(|&(_, digit)| digit);
+            assert_eq!(
+                get_digit_for_spelled_out_number(word),
+                linear,
+                "get_digit_for_spelled_out_number should match a linear scan for {word:?}"
+            );
+        }
+    }
+
     mod test_get_spelled_out_number {
         use crate::get_spelled_out_number;
 
@@ -526,6 +825,23 @@ mod test {
             )
         }
 
+        #[test]
+        pub fn test_spelled_out_number_with_index_inside_character() {
+            // Give a string with a multi-byte character and an index inside that character
+            let input = "£one";
+            let index = 1;
+
+            // When get_spelled_out_number is called
+            let actual = get_spelled_out_number(input, index);
+
+            // Then it should return None instead of panicking
+            assert_eq!(
+                actual, None,
+                "get_spelled_out_number should return \
+                None for an input of \"£one\" and an index of 1"
+            )
+        }
+
         #[test]
         pub fn test_spelled_out_number_with_index_before_start() {
             // Give a string that contains a spelled-out number and an index before its start
@@ -544,23 +860,6 @@ mod test {
         }
     }
 
-    #[test]
-    pub fn test_get_spelled_out_number_indexes() {
-        // Given a string containing overlapping spelled-out numbers
-        let input = "eightwo";
-
-        // When get_spelled_out_number_indexes is called
-        let actual = get_spelled_out_number_indexes(input);
-
-        // Then it should return a vector containing the spelled-out number starting indexes
-        assert_eq!(
-            actual,
-            vec![0, 4],
-            "get_spelled_out_number_indexes should return a vector \
-            containing 0 and 4 for an input string of \"eightwo\""
-        )
-    }
-
     #[test]
     pub fn test_replace_spelled_out() {
         // Given a string of spelled out numbers and numbers in their digital representation
@@ -577,6 +876,102 @@ mod test {
         )
     }
 
+    mod test_replace_spelled_out_overlaps {
+        use crate::replace_spelled_out;
+
+        #[test]
+        pub fn chain_of_three() {
+            // Then it should emit a digit for every word in a chain of overlapping words
+            assert_eq!(
+                replace_spelled_out("oneighthree"),
+                "183",
+                "replace_spelled_out should return \"183\" for an input of \"oneighthree\""
+            )
+        }
+
+        #[test]
+        pub fn shared_letter() {
+            // Then it should emit both digits when two words share a letter
+            assert_eq!(
+                replace_spelled_out("sevenine"),
+                "79",
+                "replace_spelled_out should return \"79\" for an input of \"sevenine\""
+            )
+        }
+
+        #[test]
+        pub fn multi_byte_characters() {
+            // Then it should pass non-ASCII characters between words through untouched
+            assert_eq!(
+                replace_spelled_out("one£two"),
+                "1£2",
+                "replace_spelled_out should return \"1£2\" for an input of \"one£two\""
+            )
+        }
+
+        #[test]
+        pub fn overlapping_body() {
+            // Then it should emit a digit for a word starting inside the previous one
+            assert_eq!(
+                replace_spelled_out("eightwone"),
+                "821",
+                "replace_spelled_out should return \"821\" for an input of \"eightwone\""
+            )
+        }
+    }
+
+    mod test_first_last_calibration_value {
+        use crate::{
+            filter_digits, filter_first_and_last, first_last_calibration_value, replace_spelled_out,
+        };
+
+        #[test]
+        pub fn matches_pipeline() {
+            // Given the lines of the sample input
+            let lines = [
+                "two1nine",
+                "eightwothree",
+                "abcone2threexyz",
+                "xtwone3four",
+                "4nineeightseven2",
+                "zoneight234",
+                "7pqrstsixteen",
+            ];
+
+            // Then it should return the same value as the replacing pipeline for every line
+            for line in lines {
+                let expected = filter_first_and_last(&filter_digits(&replace_spelled_out(line)))
+                    .parse::<u32>()
+                    .ok();
+                assert_eq!(
+                    first_last_calibration_value(line),
+                    expected,
+                    "first_last_calibration_value should match the pipeline for {line:?}"
+                );
+            }
+        }
+
+        #[test]
+        pub fn overlapping_words() {
+            // Then it should find different words when scanning forward and backward
+            assert_eq!(
+                first_last_calibration_value("eightwo"),
+                Some(82),
+                "first_last_calibration_value should return 82 for an input of \"eightwo\""
+            )
+        }
+
+        #[test]
+        pub fn no_values() {
+            // Then it should return None when the line has no digits or spelled-out numbers
+            assert_eq!(
+                first_last_calibration_value("abc"),
+                None,
+                "first_last_calibration_value should return None for an input of \"abc\""
+            )
+        }
+    }
+
     #[test]
     pub fn test_replace_spelled_out_strings() {
         // Given a vector of strings that contains spelled out and digital numerical values
@@ -1,18 +1,18 @@
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::str::FromStr;
-use util::Input;
+use util::{timed, Input};
 
-const INPUT_FILE_PATH: &str = "./dayXX/input";
+const DAY: &str = "dayXX";
 
 fn main() -> Result<()> {
-    let input = Input::load(INPUT_FILE_PATH)?;
+    let input = Input::for_day(DAY)?;
 
     println!("Part 1:");
-    println!("{}", part1(&input)?);
+    println!("{}", timed("part1", || part1(&input))?);
 
     println!("Part 2:");
-    println!("{}", part2(&input)?);
+    println!("{}", timed("part2", || part2(&input))?);
     Ok(())
 }
 
@@ -0,0 +1,194 @@
+use crate::Input;
+use anyhow::{anyhow, bail, Result};
+
+/// A single mapping range, given as `(dest_start, src_start, len)`.
+pub type Range = (u64, u64, u64);
+
+/// Represents a seed almanac: a list of seeds and an ordered chain of maps.
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<Range>>,
+}
+
+impl Almanac {
+    /// Provides a reference to the seeds listed on the first line of the almanac.
+    pub fn seeds(&self) -> &Vec<u64> {
+        &self.seeds
+    }
+
+    /// Provides a reference to the maps of the almanac, in the order they appear.
+    pub fn maps(&self) -> &Vec<Vec<Range>> {
+        &self.maps
+    }
+
+    /// Converts a value using one of the maps of the almanac.
+    ///
+    /// Values covered by a range of the map are shifted to the destination of that
+    /// range, while values that are not covered by any range map to themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The source value to convert.
+    /// * `map_index` - The index of the map to use.
+    ///
+    /// # Returns
+    ///
+    /// Returns the destination value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `map_index` is not the index of a map in the almanac.
+    pub fn map_value(&self, value: u64, map_index: usize) -> u64 {
+        self.maps[map_index]
+            .iter()
+            .find(|&&(_, src_start, len)| value >= src_start && value - src_start < len)
+            .map_or(value, |&(dest_start, src_start, _)| {
+                dest_start + (value - src_start)
+            })
+    }
+}
+
+/// Parses an almanac consisting of a `seeds:` line followed by blank-line separated maps.
+///
+/// Each map starts with a header line, like `seed-to-soil map:`, followed by one
+/// `dest_start src_start len` range per line.
+///
+/// # Arguments
+///
+/// * `input` - An `Input` instance containing the almanac.
+///
+/// # Returns
+///
+/// Returns the parsed `Almanac`, or an error if the input does not follow the format.
+pub fn parse(input: &Input) -> Result<Almanac> {
+    let mut sections = input
+        .sections(|line| line.trim().ends_with("map:"))
+        .into_iter();
+    let (_, preamble) = sections
+        .next()
+        .filter(|(header, _)| header.is_empty())
+        .ok_or_else(|| anyhow!("almanac should start with a seeds line"))?;
+    let mut preamble = preamble.into_iter().map(str::trim);
+    let seeds = preamble
+        .next()
+        .and_then(|line| line.strip_prefix("seeds:"))
+        .ok_or_else(|| anyhow!("almanac should start with a seeds line"))?
+        .split_whitespace()
+        .map(|seed| seed.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(line) = preamble.find(|line| !line.is_empty()) {
+        bail!("range found before any map header: {line}");
+    }
+
+    let maps = sections
+        .map(|(_, lines)| {
+            lines
+                .into_iter()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(parse_range)
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+/// Parses a `dest_start src_start len` line of a map into a `Range`.
+fn parse_range(line: &str) -> Result<Range> {
+    let numbers = line
+        .split_whitespace()
+        .map(|number| number.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let [dest_start, src_start, len] = numbers[..] else {
+        bail!("range should consist of three numbers: {line}");
+    };
+    Ok((dest_start, src_start, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Helper function that creates the beginning of the sample almanac
+    fn setup_almanac() -> Input {
+        Input::from_lines(&[
+            "seeds: 79 14 55 13",
+            "",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+        ])
+    }
+
+    #[test]
+    pub fn test_parse() -> Result<()> {
+        // Given the sample almanac
+        let input = setup_almanac();
+
+        // When parse is called
+        let almanac = parse(&input)?;
+
+        // Then it should contain the seeds and both maps
+        assert_eq!(
+            almanac.seeds(),
+            &vec![79, 14, 55, 13],
+            "parse should return the seeds [79, 14, 55, 13]"
+        );
+        assert_eq!(
+            almanac.maps(),
+            &vec![
+                vec![(50, 98, 2), (52, 50, 48)],
+                vec![(0, 15, 37), (37, 52, 2), (39, 0, 15)]
+            ],
+            "parse should return both maps in order"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_map_value() -> Result<()> {
+        // Given the sample almanac
+        let almanac = parse(&setup_almanac())?;
+
+        // When each seed is mapped through the seed-to-soil map
+        let actual: Vec<u64> = almanac
+            .seeds()
+            .iter()
+            .map(|&seed| almanac.map_value(seed, 0))
+            .collect();
+
+        // Then it should return the corresponding soil numbers
+        assert_eq!(
+            actual,
+            vec![81, 14, 57, 13],
+            "map_value should map the seeds to the soils [81, 14, 57, 13]"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_parse_range_before_header() {
+        // Then it should return an error for a range that belongs to no map
+        let input = Input::from_lines(&["seeds: 1", "50 98 2", "", "seed-to-soil map:"]);
+        assert_eq!(
+            parse(&input).map(|_| ()).map_err(|error| error.to_string()),
+            Err("range found before any map header: 50 98 2".to_string()),
+            "parse should report the range \"50 98 2\" before the first map header"
+        );
+    }
+
+    #[test]
+    pub fn test_parse_invalid_range() {
+        // Then it should return an error for a range with too few numbers
+        let input = Input::from_lines(&["seeds: 1", "", "seed-to-soil map:", "50 98"]);
+        assert!(
+            parse(&input).is_err(),
+            "parse should return an error for the range \"50 98\""
+        );
+    }
+}
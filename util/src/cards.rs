@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Classifies a hand of cards by the sizes of its groups of equal cards.
+///
+/// The rank goes from 0 (high card) through one pair, two pair, three of a kind,
+/// full house and four of a kind, up to 6 (five of a kind). When `joker` is set,
+/// every `'J'` acts as a wildcard that joins the largest group of other cards.
+///
+/// # Arguments
+///
+/// * `cards` - A string slice where each character is one card, like `"32T3K"`.
+/// * `joker` - Whether `'J'` cards should be treated as wildcards.
+///
+/// # Returns
+///
+/// Returns the rank of the hand as a `u8` from 0 to 6.
+pub fn hand_type(cards: &str, joker: bool) -> u8 {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for card in cards.chars() {
+        *counts.entry(card).or_default() += 1;
+    }
+    let jokers = if joker {
+        counts.remove(&'J').unwrap_or(0)
+    } else {
+        0
+    };
+
+    let mut groups: Vec<usize> = counts.into_values().collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    match groups.first_mut() {
+        Some(largest) => *largest += jokers,
+        None => groups.push(jokers),
+    }
+
+    match groups.as_slice() {
+        [5, ..] => 6,
+        [4, ..] => 5,
+        [3, 2, ..] => 4,
+        [3, ..] => 3,
+        [2, 2, ..] => 2,
+        [2, ..] => 1,
+        _ => 0,
+    }
+}
+
+/// Compares two strings character by character using a custom character order.
+///
+/// Characters earlier in `order` sort first, and characters missing from `order`
+/// sort after all of the listed ones. When one string is a prefix of the other,
+/// the shorter string sorts first.
+///
+/// # Arguments
+///
+/// * `a` - The first string to compare.
+/// * `b` - The second string to compare.
+/// * `order` - The characters in ascending order of priority.
+///
+/// # Returns
+///
+/// Returns the `Ordering` of `a` relative to `b`.
+pub fn compare_by_order(a: &str, b: &str, order: &[char]) -> Ordering {
+    let rank = |c: char| order.iter().position(|&o| o == c).unwrap_or(order.len());
+    a.chars().map(rank).cmp(b.chars().map(rank))
+}
+
+#[cfg(test)]
+mod test {
+    mod test_hand_type {
+        use crate::hand_type;
+
+        #[test]
+        pub fn five_of_a_kind() {
+            // Then it should return 6 for five equal cards
+            assert_eq!(
+                hand_type("AAAAA", false),
+                6,
+                "hand_type should return 6 for \"AAAAA\""
+            )
+        }
+
+        #[test]
+        pub fn four_of_a_kind() {
+            // Then it should return 5 for four equal cards
+            assert_eq!(
+                hand_type("AAAAK", false),
+                5,
+                "hand_type should return 5 for \"AAAAK\""
+            )
+        }
+
+        #[test]
+        pub fn joker_upgrade() {
+            // Then it should upgrade a pair with two jokers to four of a kind
+            assert_eq!(
+                hand_type("KTJJT", true),
+                5,
+                "hand_type should return 5 for \"KTJJT\" with jokers"
+            )
+        }
+
+        #[test]
+        pub fn joker_disabled() {
+            // Then it should treat 'J' as a regular card without jokers
+            assert_eq!(
+                hand_type("KTJJT", false),
+                2,
+                "hand_type should return 2 for \"KTJJT\" without jokers"
+            )
+        }
+    }
+
+    mod test_compare_by_order {
+        use crate::compare_by_order;
+        use std::cmp::Ordering;
+
+        // Card order from weakest to strongest, with 'J' as the weakest joker
+        const ORDER: [char; 13] = [
+            'J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A',
+        ];
+
+        #[test]
+        pub fn custom_order() {
+            // Then it should compare the first differing cards by the custom order
+            assert_eq!(
+                compare_by_order("KTJJT", "KK677", &ORDER),
+                Ordering::Less,
+                "compare_by_order should order \"KTJJT\" before \"KK677\""
+            )
+        }
+
+        #[test]
+        pub fn joker_sorts_first() {
+            // Then it should use the custom order rather than the alphabetical one
+            assert_eq!(
+                compare_by_order("QQQJA", "QQQ2A", &ORDER),
+                Ordering::Less,
+                "compare_by_order should order \"QQQJA\" before \"QQQ2A\""
+            )
+        }
+
+        #[test]
+        pub fn unknown_characters_sort_last() {
+            // Then it should order characters missing from the order after known ones
+            assert_eq!(
+                compare_by_order("X", "A", &ORDER),
+                Ordering::Greater,
+                "compare_by_order should order \"X\" after \"A\""
+            )
+        }
+    }
+}
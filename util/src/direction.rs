@@ -0,0 +1,155 @@
+/// Represents one of the four orthogonal directions on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Returns the direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+}
+
+/// Converts a direction character into a `Direction`.
+///
+/// Both the letter form (`'U'`, `'R'`, `'D'`, `'L'`) and the arrow form
+/// (`'^'`, `'>'`, `'v'`, `'<'`) are supported.
+///
+/// # Arguments
+///
+/// * `c` - The character to convert.
+///
+/// # Returns
+///
+/// An `Option` containing the `Direction`, or `None` if the character is not a direction.
+pub fn char_to_direction(c: char) -> Option<Direction> {
+    match c {
+        'U' | '^' => Some(Direction::Up),
+        'R' | '>' => Some(Direction::Right),
+        'D' | 'v' => Some(Direction::Down),
+        'L' | '<' => Some(Direction::Left),
+        _ => None,
+    }
+}
+
+/// Converts a `Direction` into its arrow character.
+///
+/// # Arguments
+///
+/// * `direction` - The direction to convert.
+///
+/// # Returns
+///
+/// Returns one of `'^'`, `'>'`, `'v'` and `'<'`.
+pub fn direction_to_arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_char_to_direction_arrows() {
+        // Given each of the arrow characters
+        let arrows = ['^', '>', 'v', '<'];
+
+        // When char_to_direction is called on them
+        let actual: Vec<Option<Direction>> = arrows.into_iter().map(char_to_direction).collect();
+
+        // Then it should return the corresponding directions
+        assert_eq!(
+            actual,
+            vec![
+                Some(Direction::Up),
+                Some(Direction::Right),
+                Some(Direction::Down),
+                Some(Direction::Left)
+            ],
+            "char_to_direction should return Up, Right, Down and Left for '^', '>', 'v' and '<'"
+        );
+    }
+
+    #[test]
+    pub fn test_char_to_direction_letters() {
+        // Given each of the direction letters
+        let letters = ['U', 'R', 'D', 'L'];
+
+        // When char_to_direction is called on them
+        let actual: Vec<Option<Direction>> = letters.into_iter().map(char_to_direction).collect();
+
+        // Then it should return the corresponding directions
+        assert_eq!(
+            actual,
+            vec![
+                Some(Direction::Up),
+                Some(Direction::Right),
+                Some(Direction::Down),
+                Some(Direction::Left)
+            ],
+            "char_to_direction should return Up, Right, Down and Left for 'U', 'R', 'D' and 'L'"
+        );
+    }
+
+    #[test]
+    pub fn test_char_to_direction_unknown() {
+        // Then it should return None for a character that is not a direction
+        assert_eq!(
+            char_to_direction('x'),
+            None,
+            "char_to_direction should return None for 'x'"
+        );
+    }
+
+    #[test]
+    pub fn test_direction_to_arrow() {
+        // Then it should convert every arrow back to the same character
+        for arrow in ['^', '>', 'v', '<'] {
+            assert_eq!(
+                char_to_direction(arrow).map(direction_to_arrow),
+                Some(arrow),
+                "direction_to_arrow should return '{arrow}' for the direction of '{arrow}'"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_turn_right() {
+        // Given each of the directions
+        let directions = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ];
+
+        // When turn_right is called on them
+        let actual: Vec<Direction> = directions.into_iter().map(Direction::turn_right).collect();
+
+        // Then it should return the clockwise neighbor of each direction
+        assert_eq!(
+            actual,
+            vec![
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+                Direction::Up
+            ],
+            "turn_right should turn Up, Right, Down and Left into Right, Down, Left and Up"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::io;
+use thiserror::Error;
+
+/// Errors that can occur while loading or interpreting puzzle input.
+///
+/// Line numbers are one-based, so they match what an editor shows for the
+/// input file.
+#[derive(Debug, Error)]
+pub enum InputError {
+    /// Reading the input failed.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// A line, or a value on a line, could not be parsed.
+    #[error("failed to parse line {line_no}: {content:?}")]
+    Parse {
+        line_no: usize,
+        content: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The input contains no lines, but at least one was needed.
+    #[error("the input contains no lines")]
+    EmptyInput,
+    /// A line of a grid does not have the same length as the first line.
+    #[error("line {line_no} has length {len}, expected {expected}")]
+    RaggedGrid {
+        line_no: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// A line contains characters outside of ASCII where only ASCII is supported.
+    #[error("line {line_no} contains non-ASCII characters")]
+    NonAscii { line_no: usize },
+    /// A line does not contain the expected number of values.
+    #[error("line {line_no} has {count} values, expected {expected}")]
+    ValueCount {
+        line_no: usize,
+        count: usize,
+        expected: usize,
+    },
+}
+
+impl InputError {
+    /// Creates a `Parse` error for a zero-based line index and the error of its parser.
+    pub(crate) fn parse(
+        index: usize,
+        content: &str,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        InputError::Parse {
+            line_no: index + 1,
+            content: content.to_string(),
+            source: Box::new(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_error_source() {
+        // Given a parse error created from a failed integer parse
+        let source = "x".parse::<i64>().unwrap_err();
+        let error = InputError::parse(2, "1 x", source.clone());
+
+        // Then it should name the one-based line and keep the parser's error as its source
+        assert_eq!(
+            error.to_string(),
+            "failed to parse line 3: \"1 x\"",
+            "the parse error should name line 3 and its content"
+        );
+        assert_eq!(
+            std::error::Error::source(&error).map(|source| source.to_string()),
+            Some(source.to_string()),
+            "the parse error should keep the integer parse error as its source"
+        );
+    }
+}
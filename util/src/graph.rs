@@ -0,0 +1,171 @@
+use crate::Input;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Builds an adjacency list from lines that each describe one edge, like `a-b`.
+///
+/// Node names are trimmed, and lines that do not contain the separator are skipped.
+///
+/// # Arguments
+///
+/// * `input` - An `Input` instance with one edge per line.
+/// * `sep` - The character separating the two node names of an edge.
+/// * `directed` - Whether edges only go from the first node to the second. When
+///   `false`, every edge is also added in the reverse direction.
+///
+/// # Returns
+///
+/// Returns a `HashMap` from each node to its neighbors, in the order the edges appear.
+pub fn parse_graph(input: &Input, sep: char, directed: bool) -> HashMap<String, Vec<String>> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    for line in input.lines() {
+        let Some((from, to)) = line.split_once(sep) else {
+            continue;
+        };
+        let (from, to) = (from.trim().to_string(), to.trim().to_string());
+        if !directed {
+            graph.entry(to.clone()).or_default().push(from.clone());
+        }
+        graph.entry(from).or_default().push(to);
+    }
+    graph
+}
+
+/// Finds a largest clique of an undirected graph using the Bron–Kerbosch algorithm.
+///
+/// The adjacency map is expected to be symmetric: if `b` is a neighbor of `a`,
+/// then `a` is a neighbor of `b`.
+///
+/// # Arguments
+///
+/// * `adjacency` - A map from each node to the set of its neighbors.
+///
+/// # Returns
+///
+/// Returns the nodes of a maximum clique in no particular order. When several cliques
+/// share the maximum size, any one of them may be returned.
+pub fn max_clique<N: Hash + Eq + Clone>(adjacency: &HashMap<N, HashSet<N>>) -> Vec<N> {
+    let mut best = Vec::new();
+    let candidates: HashSet<N> = adjacency.keys().cloned().collect();
+    bron_kerbosch(
+        adjacency,
+        &mut Vec::new(),
+        candidates,
+        HashSet::new(),
+        &mut best,
+    );
+    best
+}
+
+/// Extends the clique `current` with the `candidates`, skipping nodes in `excluded`,
+/// and records the largest clique found in `best`.
+fn bron_kerbosch<N: Hash + Eq + Clone>(
+    adjacency: &HashMap<N, HashSet<N>>,
+    current: &mut Vec<N>,
+    mut candidates: HashSet<N>,
+    mut excluded: HashSet<N>,
+    best: &mut Vec<N>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if current.len() > best.len() {
+            *best = current.clone();
+        }
+        return;
+    }
+    let empty = HashSet::new();
+    let neighbors = |node: &N| adjacency.get(node).unwrap_or(&empty);
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|node| neighbors(node).len())
+        .cloned()
+        .expect("candidates or excluded should not be empty");
+
+    let to_visit: Vec<N> = candidates.difference(neighbors(&pivot)).cloned().collect();
+    for node in to_visit {
+        current.push(node.clone());
+        bron_kerbosch(
+            adjacency,
+            current,
+            candidates.intersection(neighbors(&node)).cloned().collect(),
+            excluded.intersection(neighbors(&node)).cloned().collect(),
+            best,
+        );
+        current.pop();
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_max_clique() {
+        // Given a graph where a, b, c and d are all connected, with a triangle c, e, f beside it
+        let edges = [
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("c", "e"),
+            ("c", "f"),
+            ("e", "f"),
+        ];
+        let mut adjacency: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for (a, b) in edges {
+            adjacency.entry(a).or_default().insert(b);
+            adjacency.entry(b).or_default().insert(a);
+        }
+
+        // When max_clique is called
+        let mut actual = max_clique(&adjacency);
+        actual.sort();
+
+        // Then it should return the four fully connected nodes
+        assert_eq!(
+            actual,
+            vec!["a", "b", "c", "d"],
+            "max_clique should return [\"a\", \"b\", \"c\", \"d\"] for the provided graph"
+        );
+    }
+
+    #[test]
+    pub fn test_parse_graph_undirected() {
+        // Given an undirected edge list
+        let input = Input::from_lines(&["a-b", "b-c", "a-c"]);
+
+        // When parse_graph is called
+        let actual = parse_graph(&input, '-', false);
+
+        // Then every node should list the nodes it shares an edge with
+        let neighbors = |node: &str| actual.get(node).cloned().unwrap_or_default();
+        assert_eq!(
+            (neighbors("a"), neighbors("b"), neighbors("c")),
+            (
+                vec!["b".to_string(), "c".to_string()],
+                vec!["a".to_string(), "c".to_string()],
+                vec!["b".to_string(), "a".to_string()]
+            ),
+            "parse_graph should add both directions of each undirected edge"
+        );
+    }
+
+    #[test]
+    pub fn test_parse_graph_directed() {
+        // Given a directed edge list
+        let input = Input::from_lines(&["a-b", "b-c"]);
+
+        // When parse_graph is called
+        let actual = parse_graph(&input, '-', true);
+
+        // Then only the source nodes should have neighbors
+        assert_eq!(
+            (actual.get("a"), actual.get("c")),
+            (Some(&vec!["b".to_string()]), None),
+            "parse_graph should only add directed edges from source to target"
+        );
+    }
+}
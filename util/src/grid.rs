@@ -0,0 +1,1697 @@
+use crate::{Direction, Input};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Represents a rectangular two-dimensional grid of cells.
+///
+/// The cells are stored row by row in a single vector. Coordinates are given
+/// as `(x, y)`, where `x` is the column and `y` is the row, starting from the
+/// top-left corner.
+///
+/// Note that this is the reverse of the `(row, col)` order used by the coordinate
+/// helpers of `Input`, such as `Input::char_positions`. Character grids also offer
+/// `char_at` and `neighbors_at`, which take and return `(row, col)` coordinates for
+/// code that works with both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `Grid` from an `Input` by converting each character into a cell.
+    ///
+    /// The width of the grid is the length of the longest line. Lines shorter
+    /// than that are padded with cells created from a space character.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - An `Input` instance whose lines make up the rows of the grid.
+    /// * `f` - A function that converts a character into a cell value.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Grid` containing one cell for each position in the input.
+    pub fn from_input(input: &Input, f: impl Fn(char) -> T) -> Self {
+        let width = input
+            .lines()
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = input.lines().len();
+        let mut cells = Vec::with_capacity(width * height);
+        for line in input.lines() {
+            let padding = width - line.chars().count();
+            cells.extend(
+                line.chars()
+                    .chain(std::iter::repeat_n(' ', padding))
+                    .map(&f),
+            );
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Returns the number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Provides a reference to the cell at the specified coordinates.
+    ///
+    /// The column comes first, so `get(x, y)` is the cell at row `y` and column `x`.
+    /// Use `char_at` on a character grid to look cells up by `(row, col)` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the cell, or `None` if the coordinates
+    /// are out of bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the coordinates of the orthogonal neighbors of a cell that lie within the grid.
+    ///
+    /// The neighbors are returned clockwise, starting with the one above.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the in-bounds neighbor coordinates.
+    pub fn neighbor_coords4(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.offset_coords(x, y, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// Returns the coordinates of the orthogonal and diagonal neighbors of a cell
+    /// that lie within the grid.
+    ///
+    /// The neighbors are returned clockwise, starting with the one above.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the in-bounds neighbor coordinates.
+    pub fn neighbor_coords8(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.offset_coords(
+            x,
+            y,
+            &[
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+            ],
+        )
+    }
+
+    /// Provides a reference to a cell, wrapping the coordinates around the edges of the grid.
+    ///
+    /// This treats the grid as an infinitely repeating tile, so coordinates outside the
+    /// grid, including negative ones, map onto the cell at the same position in the tile.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell, which may be out of bounds.
+    /// * `y` - The row of the cell, which may be out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// Returns a reference to the cell at the wrapped coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn wrap_get(&self, x: i64, y: i64) -> &T {
+        assert!(!self.cells.is_empty(), "wrap_get called on an empty grid");
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Finds the cost of the cheapest path between two cells using A* search.
+    ///
+    /// Moving into a cell costs the amount returned by `cost` for that cell, while the
+    /// start cell itself is free. The heuristic is the Manhattan distance for orthogonal
+    /// movement and the Chebyshev distance when diagonal moves are allowed, which
+    /// assumes that entering a passable cell costs at least 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `goal` - The `(x, y)` coordinates of the cell to reach.
+    /// * `allow_diagonal` - Whether diagonal moves are allowed in addition to orthogonal ones.
+    /// * `cost` - A function returning the cost of entering a cell, or `None` if it is impassable.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the cost of the cheapest path, or `None` if the goal
+    /// cannot be reached or `start` is out of bounds.
+    pub fn astar(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        allow_diagonal: bool,
+        cost: impl Fn(&T) -> Option<u64>,
+    ) -> Option<u64> {
+        let heuristic = |(x, y): (usize, usize)| {
+            let (dx, dy) = (x.abs_diff(goal.0) as u64, y.abs_diff(goal.1) as u64);
+            if allow_diagonal {
+                dx.max(dy)
+            } else {
+                dx + dy
+            }
+        };
+        self.get(start.0, start.1)?;
+        let mut best = vec![u64::MAX; self.cells.len()];
+        let mut queue = BinaryHeap::new();
+        best[start.1 * self.width + start.0] = 0;
+        queue.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, distance, position))) = queue.pop() {
+            if position == goal {
+                return Some(distance);
+            }
+            if distance > best[position.1 * self.width + position.0] {
+                continue;
+            }
+            let neighbors = if allow_diagonal {
+                self.neighbor_coords8(position.0, position.1)
+            } else {
+                self.neighbor_coords4(position.0, position.1)
+            };
+            for (x, y) in neighbors {
+                let Some(step) = cost(&self.cells[y * self.width + x]) else {
+                    continue;
+                };
+                let next = distance + step;
+                if next < best[y * self.width + x] {
+                    best[y * self.width + x] = next;
+                    queue.push(Reverse((next + heuristic((x, y)), next, (x, y))));
+                }
+            }
+        }
+        None
+    }
+
+    /// Flood fills the passable region around a start cell, visiting at most `max` cells.
+    ///
+    /// The fill spreads orthogonally in breadth-first order, so the cells closest to the
+    /// start are visited first when the cap is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `passable` - A function deciding whether a cell can be entered.
+    /// * `max` - The maximum number of cells to visit.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `HashSet` of the visited coordinates, which is empty if the start cell
+    /// is not passable.
+    pub fn flood_limited(
+        &self,
+        start: (usize, usize),
+        passable: impl Fn(&T) -> bool,
+        max: usize,
+    ) -> HashSet<(usize, usize)> {
+        let mut visited = HashSet::new();
+        if max == 0 || !self.get(start.0, start.1).is_some_and(&passable) {
+            return visited;
+        }
+        let mut queue = VecDeque::from([start]);
+        visited.insert(start);
+
+        while let Some((x, y)) = queue.pop_front() {
+            for (nx, ny) in self.neighbor_coords4(x, y) {
+                if visited.len() == max {
+                    return visited;
+                }
+                if passable(&self.cells[ny * self.width + nx]) && visited.insert((nx, ny)) {
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        visited
+    }
+
+    /// Returns the coordinates of the orthogonal neighbors of a cell, wrapping around the
+    /// edges of the grid.
+    ///
+    /// The neighbors are returned clockwise, starting with the one above.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    ///
+    /// # Returns
+    ///
+    /// Returns the four wrapped neighbor coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn neighbors4_torus(&self, x: usize, y: usize) -> [(usize, usize); 4] {
+        assert!(
+            !self.cells.is_empty(),
+            "neighbors4_torus called on an empty grid"
+        );
+        let (w, h) = (self.width, self.height);
+        [
+            (x, (y + h - 1) % h),
+            ((x + 1) % w, y),
+            (x, (y + 1) % h),
+            ((x + w - 1) % w, y),
+        ]
+    }
+
+    /// Counts the edges of a region that border a cell outside the region or the grid edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The `(x, y)` coordinates of the cells that make up the region.
+    ///
+    /// # Returns
+    ///
+    /// Returns the perimeter of the region.
+    pub fn region_perimeter(&self, region: &HashSet<(usize, usize)>) -> usize {
+        region
+            .iter()
+            .map(|&(x, y)| {
+                4 - self
+                    .neighbor_coords4(x, y)
+                    .iter()
+                    .filter(|neighbor| region.contains(neighbor))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Returns the coordinates of the neighbors of a cell that lie within the grid.
+    ///
+    /// Both the cell and the returned neighbors use `(x, y)` coordinates, with the
+    /// column first. Use `neighbors_at` on a character grid for `(row, col)` coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the cell.
+    /// * `y` - The row of the cell.
+    /// * `diagonal` - Whether diagonal neighbors should be included.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the in-bounds neighbor coordinates, as returned
+    /// by `neighbor_coords8` when `diagonal` is set and by `neighbor_coords4` otherwise.
+    pub fn neighbors(&self, x: usize, y: usize, diagonal: bool) -> Vec<(usize, usize)> {
+        if diagonal {
+            self.neighbor_coords8(x, y)
+        } else {
+            self.neighbor_coords4(x, y)
+        }
+    }
+
+    /// Iterates over every cell of the grid together with its coordinates.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding `(x, y, cell)` tuples in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (index % self.width, index / self.width, cell))
+    }
+
+    /// Returns the coordinates of the neighboring cell in a direction, if it is in bounds.
+    fn step(&self, (x, y): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let (dx, dy) = match direction {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        };
+        self.offset_coords(x, y, &[(dx, dy)]).pop()
+    }
+
+    /// Applies each offset to the given coordinates, keeping only those within the grid.
+    fn offset_coords(&self, x: usize, y: usize, offsets: &[(isize, isize)]) -> Vec<(usize, usize)> {
+        offsets
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < self.width && ny < self.height).then_some((nx, ny))
+            })
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Provides the character at the specified row and column.
+    ///
+    /// This is `get` with the coordinates in the `(row, col)` order used by `Input`.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell.
+    /// * `col` - The column of the cell.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the character, or `None` if the coordinates are out of
+    /// bounds.
+    pub fn char_at(&self, row: usize, col: usize) -> Option<char> {
+        self.get(col, row).copied()
+    }
+
+    /// Returns the `(row, col)` coordinates of the neighbors of a cell that lie within
+    /// the grid.
+    ///
+    /// This is `neighbors` with the coordinates in the `(row, col)` order used by `Input`.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell.
+    /// * `col` - The column of the cell.
+    /// * `diagonal` - Whether diagonal neighbors should be included.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<(usize, usize)>` containing the in-bounds neighbors as `(row, col)` pairs.
+    pub fn neighbors_at(&self, row: usize, col: usize, diagonal: bool) -> Vec<(usize, usize)> {
+        self.neighbors(col, row, diagonal)
+            .into_iter()
+            .map(|(x, y)| (y, x))
+            .collect()
+    }
+
+    /// Converts the grid back into an `Input`, with one line per row.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Input` instance whose lines are the rows of the grid.
+    pub fn to_input(&self) -> Input {
+        let rows: Vec<String> = (0..self.height)
+            .map(|y| {
+                self.cells[y * self.width..(y + 1) * self.width]
+                    .iter()
+                    .collect()
+            })
+            .collect();
+        let lines: Vec<&str> = rows.iter().map(|row| row.as_str()).collect();
+        Input::from_lines(&lines)
+    }
+
+    /// Finds the fewest steps to a goal with a breadth-first search over positions and states.
+    ///
+    /// The state travels along with the search, so the same cell may be visited again
+    /// with a different state, such as a different set of collected keys. Each step
+    /// moves orthogonally to a neighboring cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `initial_state` - The state at the start cell.
+    /// * `transition` - A function returning the state after entering the given cell with
+    ///   the given state, or `None` if the cell cannot be entered.
+    /// * `is_goal` - A function deciding whether a cell and state combination is a goal.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number of steps to the nearest goal, or `None` if no
+    /// goal can be reached or `start` is out of bounds.
+    pub fn bfs_with_state<S: Hash + Eq + Clone>(
+        &self,
+        start: (usize, usize),
+        initial_state: S,
+        transition: impl Fn((usize, usize), &S) -> Option<S>,
+        is_goal: impl Fn((usize, usize), &S) -> bool,
+    ) -> Option<usize> {
+        self.get(start.0, start.1)?;
+        let mut visited = HashSet::from([(start, initial_state.clone())]);
+        let mut queue = VecDeque::from([(start, initial_state, 0)]);
+
+        while let Some((position, state, steps)) = queue.pop_front() {
+            if is_goal(position, &state) {
+                return Some(steps);
+            }
+            for neighbor in self.neighbor_coords4(position.0, position.1) {
+                let Some(next) = transition(neighbor, &state) else {
+                    continue;
+                };
+                if visited.insert((neighbor, next.clone())) {
+                    queue.push_back((neighbor, next, steps + 1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Performs one spin cycle, rolling the rounded rocks north, west, south and east.
+    ///
+    /// Rounded rocks are `O` cells, which roll over empty `.` cells until they are
+    /// stopped by the edge of the grid, a cube-shaped `#` rock or another rounded rock.
+    pub fn spin_cycle(&mut self) {
+        for direction in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    /// Calculates the total load of the rounded rocks on the north side of the grid.
+    ///
+    /// # Returns
+    ///
+    /// Returns the sum over all rounded `O` rocks of the number of rows from the rock
+    /// to the south edge, including the rock's own row.
+    pub fn total_load(&self) -> usize {
+        self.enumerate()
+            .filter(|&(_, _, &cell)| cell == 'O')
+            .map(|(_, y, _)| self.height - y)
+            .sum()
+    }
+
+    /// Counts the cells energized by a beam of light travelling through the grid.
+    ///
+    /// The beam passes through empty `.` cells, is reflected by 90 degrees by the
+    /// mirrors `/` and `\`, and is split in two by a `|` or `-` splitter it hits
+    /// from the side. A splitter hit end-on is passed through like an empty cell. The
+    /// simulation stops once every beam has left the grid or repeats a cell and
+    /// direction that was already visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the first cell the beam enters, and
+    ///   the direction it travels in.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of distinct cells the beam passes through, which is 0 if
+    /// the start cell is out of bounds.
+    pub fn energized_count(&self, start: ((usize, usize), Direction)) -> usize {
+        let mut visited = HashSet::new();
+        let mut beams = vec![start];
+        while let Some(((x, y), direction)) = beams.pop() {
+            let Some(&cell) = self.get(x, y) else {
+                continue;
+            };
+            if !visited.insert(((x, y), direction)) {
+                continue;
+            }
+            let next = match (cell, direction) {
+                ('/', Direction::Right) | ('\\', Direction::Left) => vec![Direction::Up],
+                ('/', Direction::Left) | ('\\', Direction::Right) => vec![Direction::Down],
+                ('/', Direction::Up) | ('\\', Direction::Down) => vec![Direction::Right],
+                ('/', Direction::Down) | ('\\', Direction::Up) => vec![Direction::Left],
+                ('|', Direction::Left | Direction::Right) => vec![Direction::Up, Direction::Down],
+                ('-', Direction::Up | Direction::Down) => vec![Direction::Left, Direction::Right],
+                _ => vec![direction],
+            };
+            for direction in next {
+                if let Some(position) = self.step((x, y), direction) {
+                    beams.push((position, direction));
+                }
+            }
+        }
+        visited
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Rolls every rounded `O` rock as far as it goes in a direction.
+    fn tilt(&mut self, direction: Direction) {
+        let (lines, length) = match direction {
+            Direction::Up | Direction::Down => (self.width, self.height),
+            Direction::Left | Direction::Right => (self.height, self.width),
+        };
+        let width = self.width;
+        let index = |line: usize, position: usize| match direction {
+            Direction::Up => position * width + line,
+            Direction::Down => (length - 1 - position) * width + line,
+            Direction::Left => line * width + position,
+            Direction::Right => line * width + length - 1 - position,
+        };
+        for line in 0..lines {
+            let mut free = 0;
+            for position in 0..length {
+                match self.cells[index(line, position)] {
+                    '#' => free = position + 1,
+                    'O' => {
+                        self.cells.swap(index(line, position), index(line, free));
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl Grid<u64> {
+    /// Finds the minimal cost of reaching any goal cell using Dijkstra's algorithm.
+    ///
+    /// Each cell holds the cost of moving into it with an orthogonal step, while the
+    /// start cell itself is free.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `is_goal` - A function deciding whether the cell at the given coordinates is a goal.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the cost of the cheapest path to any goal cell, or `None`
+    /// if no goal can be reached or `start` is out of bounds.
+    pub fn cost_to_any(
+        &self,
+        start: (usize, usize),
+        is_goal: impl Fn((usize, usize)) -> bool,
+    ) -> Option<u64> {
+        self.dijkstra(start, is_goal).map(|(cost, _)| cost)
+    }
+
+    /// Finds the cells of a minimal-cost path between two cells using Dijkstra's algorithm.
+    ///
+    /// Costs are counted as in `cost_to_any`. When several paths share the minimal
+    /// cost, any one of them may be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `goal` - The `(x, y)` coordinates of the cell to reach.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the `(x, y)` coordinates of every cell on the path, from
+    /// `start` to `goal` inclusive, or `None` if the goal cannot be reached or `start`
+    /// is out of bounds.
+    pub fn shortest_path_cells(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+    ) -> Option<Vec<(usize, usize)>> {
+        self.dijkstra(start, |position| position == goal)
+            .map(|(_, path)| path)
+    }
+
+    /// Runs Dijkstra's algorithm until a goal is reached, returning its cost and path.
+    fn dijkstra(
+        &self,
+        start: (usize, usize),
+        is_goal: impl Fn((usize, usize)) -> bool,
+    ) -> Option<(u64, Vec<(usize, usize)>)> {
+        self.get(start.0, start.1)?;
+        let mut best = vec![u64::MAX; self.cells.len()];
+        let mut previous = vec![None; self.cells.len()];
+        let mut queue = BinaryHeap::new();
+        best[start.1 * self.width + start.0] = 0;
+        queue.push(Reverse((0, start)));
+
+        while let Some(Reverse((distance, position))) = queue.pop() {
+            if is_goal(position) {
+                let mut path = vec![position];
+                let mut current = position;
+                while let Some(before) = previous[current.1 * self.width + current.0] {
+                    path.push(before);
+                    current = before;
+                }
+                path.reverse();
+                return Some((distance, path));
+            }
+            if distance > best[position.1 * self.width + position.0] {
+                continue;
+            }
+            for (x, y) in self.neighbor_coords4(position.0, position.1) {
+                let next = distance + self.cells[y * self.width + x];
+                if next < best[y * self.width + x] {
+                    best[y * self.width + x] = next;
+                    previous[y * self.width + x] = Some(position);
+                    queue.push(Reverse((next, (x, y))));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Grid<bool> {
+    /// Counts the cells that can be reached from a start cell within a number of steps.
+    ///
+    /// Cells holding `true` are passable and each step moves orthogonally to a passable
+    /// cell. Since it is always possible to step back and forth, a cell reached in `d`
+    /// steps can also be reached in exactly `d + 2`, `d + 4` and so on.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of the cell to start from.
+    /// * `steps` - The maximum number of steps to take.
+    /// * `exact_parity` - Whether to only count cells that can be reached in exactly
+    ///   `steps` steps, i.e. those whose distance has the same parity as `steps`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of cells, including the start cell when it qualifies, or 0 if
+    /// `start` is out of bounds.
+    pub fn reachable_count(
+        &self,
+        start: (usize, usize),
+        steps: usize,
+        exact_parity: bool,
+    ) -> usize {
+        if self.get(start.0, start.1).is_none() {
+            return 0;
+        }
+        let mut distances = vec![usize::MAX; self.cells.len()];
+        let mut queue = VecDeque::from([start]);
+        distances[start.1 * self.width + start.0] = 0;
+        let mut count = 0;
+
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances[y * self.width + x];
+            if !exact_parity || distance % 2 == steps % 2 {
+                count += 1;
+            }
+            if distance == steps {
+                continue;
+            }
+            for (nx, ny) in self.neighbor_coords4(x, y) {
+                let index = ny * self.width + nx;
+                if self.cells[index] && distances[index] == usize::MAX {
+                    distances[index] = distance + 1;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        count
+    }
+}
+
+impl Grid<Direction> {
+    /// Rotates the grid 90 degrees clockwise, turning every direction along with it.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Grid` with the width and height swapped, where each cell has
+    /// moved to its rotated position and points in its rotated direction.
+    pub fn rotate_cw(&self) -> Grid<Direction> {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| self.cells[y * self.width + x].turn_right())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Counts the cell sides that separate differing values.
+    ///
+    /// Every pair of orthogonally adjacent cells holding different values counts
+    /// once, and every cell side on the edge of the grid counts once as well, as if the
+    /// grid were surrounded by a value that differs from all cells.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of boundary edges in the grid.
+    pub fn boundary_edges(&self) -> usize {
+        if self.cells.is_empty() {
+            return 0;
+        }
+        let outer = 2 * (self.width + self.height);
+        let inner = self
+            .enumerate()
+            .map(|(x, y, value)| {
+                let right = x + 1 < self.width && self.cells[y * self.width + x + 1] != *value;
+                let down = y + 1 < self.height && self.cells[(y + 1) * self.width + x] != *value;
+                usize::from(right) + usize::from(down)
+            })
+            .sum::<usize>();
+        outer + inner
+    }
+
+    /// Counts the connected regions of equal cells.
+    ///
+    /// Cells belong to the same region when a path of orthogonal steps between
+    /// equal cells connects them, so two separate areas of the same value count as
+    /// two regions.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of regions in the grid.
+    pub fn component_count(&self) -> usize {
+        self.label_regions()
+            .cells
+            .iter()
+            .max()
+            .map_or(0, |&label| label + 1)
+    }
+
+    /// Labels every cell with the id of the connected region of equal cells it belongs to.
+    ///
+    /// Regions are found as in `component_count` and numbered from 0 in the order
+    /// their first cell appears in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Grid<usize>` of the same size holding the region id of every cell.
+    pub fn label_regions(&self) -> Grid<usize> {
+        let mut labels: Vec<Option<usize>> = vec![None; self.cells.len()];
+        let mut count = 0;
+        for start in 0..self.cells.len() {
+            if labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(count);
+            let mut stack = vec![(start % self.width, start / self.width)];
+            while let Some((x, y)) = stack.pop() {
+                for (nx, ny) in self.neighbor_coords4(x, y) {
+                    let index = ny * self.width + nx;
+                    if labels[index].is_none() && self.cells[index] == self.cells[start] {
+                        labels[index] = Some(count);
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            count += 1;
+        }
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: labels.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Finds a horizontal line across which the grid reflects.
+    ///
+    /// The line lies between two rows, and only the rows that have a counterpart on
+    /// the other side of the line are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `smudges` - The exact number of cells that must differ from their reflection.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number of rows above the first matching line, or
+    /// `None` if no line reflects the grid with exactly `smudges` differences.
+    pub fn horizontal_mirror(&self, smudges: usize) -> Option<usize> {
+        (1..self.height).find(|&line| {
+            let differences: usize = (0..line.min(self.height - line))
+                .map(|offset| {
+                    let above = (line - 1 - offset) * self.width;
+                    let below = (line + offset) * self.width;
+                    (0..self.width)
+                        .filter(|&x| self.cells[above + x] != self.cells[below + x])
+                        .count()
+                })
+                .sum();
+            differences == smudges
+        })
+    }
+
+    /// Finds a vertical line across which the grid reflects.
+    ///
+    /// The line lies between two columns, and only the columns that have a
+    /// counterpart on the other side of the line are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `smudges` - The exact number of cells that must differ from their reflection.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the number of columns left of the first matching line,
+    /// or `None` if no line reflects the grid with exactly `smudges` differences.
+    pub fn vertical_mirror(&self, smudges: usize) -> Option<usize> {
+        (1..self.width).find(|&line| {
+            let differences: usize = (0..line.min(self.width - line))
+                .map(|offset| {
+                    let left = line - 1 - offset;
+                    let right = line + offset;
+                    (0..self.height)
+                        .filter(|&y| {
+                            self.cells[y * self.width + left] != self.cells[y * self.width + right]
+                        })
+                        .count()
+                })
+                .sum();
+            differences == smudges
+        })
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Replaces the connected region of cells equal to the start cell with a new value.
+    ///
+    /// The region spreads orthogonally. Nothing changes if the start cell is out of
+    /// bounds or already holds the new value.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The `(x, y)` coordinates of a cell in the region to fill.
+    /// * `new` - The value to fill the region with.
+    pub fn bucket_fill(&mut self, start: (usize, usize), new: T) {
+        let Some(original) = self.get(start.0, start.1).cloned() else {
+            return;
+        };
+        if original == new {
+            return;
+        }
+        let mut stack = vec![start];
+        self.cells[start.1 * self.width + start.0] = new.clone();
+        while let Some((x, y)) = stack.pop() {
+            for (nx, ny) in self.neighbor_coords4(x, y) {
+                let index = ny * self.width + nx;
+                if self.cells[index] == original {
+                    self.cells[index] = new.clone();
+                    stack.push((nx, ny));
+                }
+            }
+        }
+    }
+}
+
+impl From<&Input> for Grid<char> {
+    /// Creates a character `Grid` from an `Input`, padding shorter lines with spaces.
+    fn from(input: &Input) -> Self {
+        Grid::from_input(input, |c| c)
+    }
+}
+
+/// Turns the rows of a character grid into its columns.
+///
+/// The first output line holds the first character of every input line, the
+/// second output line the second characters, and so on. Lines shorter than the
+/// longest line are padded with spaces, so the result is always rectangular.
+///
+/// # Arguments
+///
+/// * `lines` - The rows of the grid, from top to bottom.
+///
+/// # Returns
+///
+/// Returns a `Vec<String>` with one line per column, from left to right.
+pub fn transpose(lines: &[String]) -> Vec<String> {
+    transpose_iter(lines).collect()
+}
+
+/// Lazily turns the rows of a character grid into its columns, as `transpose` does.
+pub(crate) fn transpose_iter(lines: &[String]) -> impl Iterator<Item = String> + '_ {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut rows: Vec<_> = lines.iter().map(|line| line.chars()).collect();
+    (0..width).map(move |_| {
+        rows.iter_mut()
+            .map(|row| row.next().unwrap_or(' '))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::char_to_direction;
+
+    // Helper function that creates a 3x3 grid of characters
+    fn setup_grid() -> Grid<char> {
+        Grid::from_input(&Input::from_lines(&["abc", "def", "ghi"]), |c| c)
+    }
+
+    mod test_neighbor_coords4 {
+        use super::setup_grid;
+
+        #[test]
+        pub fn corner_cell() {
+            // Then it should only return the in-bounds neighbors of a corner cell
+            assert_eq!(
+                setup_grid().neighbor_coords4(0, 0),
+                vec![(1, 0), (0, 1)],
+                "neighbor_coords4 should return [(1, 0), (0, 1)] for (0, 0)"
+            )
+        }
+
+        #[test]
+        pub fn center_cell() {
+            // Then it should return all four neighbors of a center cell
+            assert_eq!(
+                setup_grid().neighbor_coords4(1, 1),
+                vec![(1, 0), (2, 1), (1, 2), (0, 1)],
+                "neighbor_coords4 should return four neighbors for (1, 1)"
+            )
+        }
+    }
+
+    mod test_neighbor_coords8 {
+        use super::setup_grid;
+
+        #[test]
+        pub fn corner_cell() {
+            // Then it should only return the in-bounds neighbors of a corner cell
+            assert_eq!(
+                setup_grid().neighbor_coords8(2, 2),
+                vec![(2, 1), (1, 2), (1, 1)],
+                "neighbor_coords8 should return [(2, 1), (1, 2), (1, 1)] for (2, 2)"
+            )
+        }
+
+        #[test]
+        pub fn center_cell() {
+            // Then it should return all eight neighbors of a center cell
+            assert_eq!(
+                setup_grid().neighbor_coords8(1, 1).len(),
+                8,
+                "neighbor_coords8 should return eight neighbors for (1, 1)"
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_to_input() {
+        // Given a rectangular input
+        let input = Input::from_lines(&["#.#", "...", ".#."]);
+
+        // When it is converted into a grid and back
+        let actual = Grid::from_input(&input, |c| c).to_input();
+
+        // Then it should contain the original lines
+        assert_eq!(
+            actual.lines(),
+            input.lines(),
+            "to_input should return the lines the grid was created from"
+        );
+    }
+
+    mod test_wrap_get {
+        use super::setup_grid;
+
+        #[test]
+        pub fn negative_coordinates() {
+            // Then it should wrap negative coordinates to the opposite edge
+            assert_eq!(
+                setup_grid().wrap_get(-1, -1),
+                &'i',
+                "wrap_get should return 'i' for (-1, -1)"
+            )
+        }
+
+        #[test]
+        pub fn overflowing_coordinates() {
+            // Then it should wrap coordinates past the edge back to the start
+            assert_eq!(
+                setup_grid().wrap_get(4, 6),
+                &'b',
+                "wrap_get should return 'b' for (4, 6)"
+            )
+        }
+    }
+
+    mod test_astar {
+        use crate::{Grid, Input};
+
+        // Helper function that creates a grid with a wall blocking the direct route
+        fn setup_maze() -> Grid<char> {
+            Grid::from_input(&Input::from_lines(&["S.#.", "..#G", "...."]), |c| c)
+        }
+
+        // Helper function that makes every cell except walls cost 1
+        fn cost(cell: &char) -> Option<u64> {
+            (*cell != '#').then_some(1)
+        }
+
+        #[test]
+        pub fn orthogonal_moves() {
+            // Then it should find the cheapest path around the obstacle
+            assert_eq!(
+                setup_maze().astar((0, 0), (3, 1), false, cost),
+                Some(6),
+                "astar should return 6 for orthogonal moves around the wall"
+            )
+        }
+
+        #[test]
+        pub fn diagonal_moves() {
+            // Then it should find a shorter path when diagonal moves are allowed
+            assert_eq!(
+                setup_maze().astar((0, 0), (3, 1), true, cost),
+                Some(3),
+                "astar should return 3 for diagonal moves around the wall"
+            )
+        }
+
+        #[test]
+        pub fn unreachable_goal() {
+            // Then it should return None when the goal is walled off
+            let grid = Grid::from_input(&Input::from_lines(&["S#G"]), |c| c);
+            assert_eq!(
+                grid.astar((0, 0), (2, 0), true, cost),
+                None,
+                "astar should return None for an unreachable goal"
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_cost_to_any() {
+        // Given a cost grid with a cheap goal far away and an expensive goal close by
+        let input = Input::from_lines(&["1191", "1191", "1111"]);
+        let grid = Grid::from_input(&input, |c| c.to_digit(10).unwrap() as u64);
+        let goals = [(1, 2), (3, 0)];
+
+        // When cost_to_any is called
+        let actual = grid.cost_to_any((0, 0), |position| goals.contains(&position));
+
+        // Then it should return the cost of reaching the cheapest goal
+        assert_eq!(
+            actual,
+            Some(3),
+            "cost_to_any should return 3 for reaching the goal at (1, 2)"
+        );
+    }
+
+    #[test]
+    pub fn test_shortest_path_cells() {
+        // Given a cost grid where the cheapest route avoids the expensive column
+        let input = Input::from_lines(&["1191", "1191", "1111"]);
+        let grid = Grid::from_input(&input, |c| c.to_digit(10).unwrap() as u64);
+
+        // When shortest_path_cells is called
+        let actual = grid.shortest_path_cells((0, 0), (3, 0)).unwrap();
+
+        // Then the path should run from start to goal through adjacent cells
+        assert_eq!(
+            (actual.first(), actual.last()),
+            (Some(&(0, 0)), Some(&(3, 0))),
+            "shortest_path_cells should start at (0, 0) and end at (3, 0)"
+        );
+        assert!(
+            actual
+                .windows(2)
+                .all(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1) == 1),
+            "shortest_path_cells should return a chain of adjacent cells"
+        );
+        // And its cost should be the minimal cost
+        let cost: u64 = actual[1..]
+            .iter()
+            .map(|&(x, y)| *grid.get(x, y).unwrap())
+            .sum();
+        assert_eq!(
+            cost, 7,
+            "shortest_path_cells should return a path of cost 7 avoiding the 9s"
+        );
+    }
+
+    #[test]
+    pub fn test_searches_out_of_bounds_start() {
+        // Given small grids and a start outside of them
+        let input = Input::from_lines(&["11", "11"]);
+        let costs = Grid::from_input(&input, |c| c.to_digit(10).unwrap() as u64);
+        let garden = Grid::from_input(&input, |_| true);
+        let chars = Grid::from(&input);
+        let start = (2, 0);
+
+        // Then the searches should report nothing instead of panicking
+        assert_eq!(
+            (
+                costs.astar(start, (0, 0), false, |&cost| Some(cost)),
+                costs.cost_to_any(start, |_| true),
+                costs.shortest_path_cells(start, (0, 0)),
+                garden.reachable_count(start, 2, false),
+                chars.bfs_with_state(start, (), |_, _| Some(()), |_, _| true),
+            ),
+            (None, None, None, 0, None),
+            "searches should return None or 0 for a start at (2, 0) in a 2x2 grid"
+        );
+    }
+
+    mod test_reachable_count {
+        use crate::{Grid, Input};
+
+        // Helper function that creates the sample garden, with the start at (5, 5)
+        fn setup_garden() -> Grid<bool> {
+            let input = Input::from_lines(&[
+                "...........",
+                ".....###.#.",
+                ".###.##..#.",
+                "..#.#...#..",
+                "....#.#....",
+                ".##..S####.",
+                ".##..#...#.",
+                ".......##..",
+                ".##.#.####.",
+                ".##..##.##.",
+                "...........",
+            ]);
+            Grid::from_input(&input, |c| c != '#')
+        }
+
+        #[test]
+        pub fn exact_parity() {
+            // Then it should count the plots reachable in exactly the given steps
+            let garden = setup_garden();
+            for (steps, expected) in [(1, 2), (2, 4), (3, 6), (6, 16)] {
+                assert_eq!(
+                    garden.reachable_count((5, 5), steps, true),
+                    expected,
+                    "reachable_count should return {expected} for exactly {steps} steps"
+                );
+            }
+        }
+
+        #[test]
+        pub fn any_parity() {
+            // Then it should count every plot reachable within the given steps
+            assert_eq!(
+                setup_garden().reachable_count((5, 5), 6, false),
+                29,
+                "reachable_count should return 29 for at most 6 steps"
+            )
+        }
+    }
+
+    mod test_flood_limited {
+        use crate::{Grid, Input};
+
+        #[test]
+        pub fn stops_at_cap() {
+            // Given a large open region
+            let row = ".".repeat(50);
+            let input = Input::from_lines(&vec![row.as_str(); 50]);
+            let grid = Grid::from_input(&input, |c| c);
+
+            // Then it should stop visiting cells once the cap is reached
+            assert_eq!(
+                grid.flood_limited((25, 25), |&c| c == '.', 100).len(),
+                100,
+                "flood_limited should visit exactly 100 cells of a 50x50 open region"
+            )
+        }
+
+        #[test]
+        pub fn region_smaller_than_cap() {
+            // Given a small region enclosed by walls
+            let input = Input::from_lines(&["..#..", "..#..", "###.."]);
+            let grid = Grid::from_input(&input, |c| c);
+
+            // Then it should visit the whole region
+            assert_eq!(
+                grid.flood_limited((0, 0), |&c| c == '.', 100).len(),
+                4,
+                "flood_limited should visit the 4 cells of the enclosed region"
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_neighbors4_torus() {
+        // Given the top-left corner cell of a 3x3 grid
+        let grid = setup_grid();
+
+        // When neighbors4_torus is called
+        let actual = grid.neighbors4_torus(0, 0);
+
+        // Then the neighbors past the edges should wrap to the opposite edges
+        assert_eq!(
+            actual,
+            [(0, 2), (1, 0), (0, 1), (2, 0)],
+            "neighbors4_torus should return [(0, 2), (1, 0), (0, 1), (2, 0)] for (0, 0)"
+        );
+    }
+
+    #[test]
+    pub fn test_rotate_cw() {
+        // Given a 3x2 grid of arrows
+        let arrows = |lines: &[&str]| {
+            Grid::from_input(&Input::from_lines(lines), |c| char_to_direction(c).unwrap())
+        };
+        let grid = arrows(&["^>v", "<<^"]);
+
+        // When rotate_cw is called
+        let actual = grid.rotate_cw();
+
+        // Then it should return a 2x3 grid with repositioned and turned arrows
+        assert_eq!(
+            actual,
+            arrows(&["^>", "^v", "><"]),
+            "rotate_cw should return [\"^>\", \"^v\", \"><\"] for [\"^>v\", \"<<^\"]"
+        );
+    }
+
+    #[test]
+    pub fn test_bucket_fill() {
+        // Given a grid with two separate regions of '.' cells
+        let mut grid = Grid::from_input(&Input::from_lines(&["..#.", ".##.", "#..."]), |c| c);
+
+        // When bucket_fill is called on the top-left region
+        grid.bucket_fill((0, 0), 'o');
+
+        // Then only the connected region around the start cell should be filled
+        assert_eq!(
+            grid.to_input().lines(),
+            &vec!["oo#.", "o##.", "#..."],
+            "bucket_fill should only fill the top-left region"
+        );
+    }
+
+    mod test_region_perimeter {
+        use crate::{Grid, Input};
+        use std::collections::HashSet;
+
+        #[test]
+        pub fn square_region() {
+            // Given a 2x2 square region in the middle of a grid
+            let grid =
+                Grid::from_input(&Input::from_lines(&["....", ".AA.", ".AA.", "...."]), |c| c);
+            let region = HashSet::from([(1, 1), (2, 1), (1, 2), (2, 2)]);
+
+            // Then its perimeter should be 8
+            assert_eq!(
+                grid.region_perimeter(&region),
+                8,
+                "region_perimeter should return 8 for a 2x2 square region"
+            )
+        }
+
+        #[test]
+        pub fn region_at_grid_edge() {
+            // Given a region in the corner of a grid
+            let grid = Grid::from_input(&Input::from_lines(&["AA.", "A.."]), |c| c);
+            let region = HashSet::from([(0, 0), (1, 0), (0, 1)]);
+
+            // Then the grid edges should count towards its perimeter
+            assert_eq!(
+                grid.region_perimeter(&region),
+                8,
+                "region_perimeter should return 8 for an L-shaped corner region"
+            )
+        }
+    }
+
+    mod test_bfs_with_state {
+        use crate::{Grid, Input};
+
+        // Helper function that creates a maze with a single route to the goal
+        fn setup_maze() -> Grid<char> {
+            Grid::from_input(&Input::from_lines(&["S.#", "#.#", "#.G"]), |c| c)
+        }
+
+        #[test]
+        pub fn stateless_maze() {
+            // Given a maze where the state never changes
+            let maze = setup_maze();
+
+            // When bfs_with_state is called with a unit state
+            let actual = maze.bfs_with_state(
+                (0, 0),
+                (),
+                |(x, y), _| (maze.get(x, y) != Some(&'#')).then_some(()),
+                |(x, y), _| maze.get(x, y) == Some(&'G'),
+            );
+
+            // Then it should behave like a plain breadth-first search
+            assert_eq!(
+                actual,
+                Some(4),
+                "bfs_with_state should return 4 for the provided maze"
+            );
+        }
+
+        #[test]
+        pub fn state_required_for_goal() {
+            // Given a maze where the goal only counts after passing a key at (1, 2)
+            let maze = setup_maze();
+
+            // When bfs_with_state is called with a flag for the collected key
+            let actual = maze.bfs_with_state(
+                (0, 0),
+                false,
+                |(x, y), &key| (maze.get(x, y) != Some(&'#')).then_some(key || (x, y) == (1, 2)),
+                |(x, y), &key| key && maze.get(x, y) == Some(&'S') && (x, y) == (0, 0),
+            );
+
+            // Then it should revisit cells with the new state to return to the start
+            assert_eq!(
+                actual,
+                Some(6),
+                "bfs_with_state should return 6 for fetching the key and returning"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_from_input_dimensions() {
+        // Given a rectangular input of two lines of four characters
+        let input = Input::from_lines(&["abcd", "efgh"]);
+
+        // When a Grid is created from it
+        let grid = Grid::from(&input);
+
+        // Then it should have the dimensions and cells of the input
+        assert_eq!(
+            (grid.width(), grid.height(), grid.get(2, 1), grid.get(4, 0)),
+            (4, 2, Some(&'g'), None),
+            "Grid should be 4x2 with 'g' at (2, 1) and nothing at (4, 0)"
+        );
+    }
+
+    #[test]
+    pub fn test_from_input_ragged() {
+        // Given an input whose lines differ in length
+        let input = Input::from_lines(&["abc", "d", ""]);
+
+        // When a Grid is created from it
+        let grid = Grid::from(&input);
+
+        // Then the shorter lines should be padded with spaces up to the longest line
+        assert_eq!(
+            grid.to_input().lines(),
+            &vec!["abc", "d  ", "   "],
+            "Grid should pad [\"abc\", \"d\", \"\"] to [\"abc\", \"d  \", \"   \"]"
+        );
+    }
+
+    #[test]
+    pub fn test_neighbors() {
+        // Given a 3x3 grid
+        let grid = setup_grid();
+
+        // Then it should include diagonal neighbors only when asked to
+        assert_eq!(
+            (grid.neighbors(0, 0, false), grid.neighbors(0, 0, true)),
+            (vec![(1, 0), (0, 1)], vec![(1, 0), (1, 1), (0, 1)]),
+            "neighbors should return 2 orthogonal or 3 total neighbors for (0, 0)"
+        );
+    }
+
+    #[test]
+    pub fn test_row_col_accessors() {
+        // Given a grid that is wider than it is tall
+        let grid = Grid::from(&Input::from_lines(&["abc", "def"]));
+
+        // Then char_at and neighbors_at should take and return (row, col) coordinates
+        assert_eq!(
+            (
+                grid.char_at(0, 2),
+                grid.char_at(2, 0),
+                grid.neighbors_at(1, 2, false)
+            ),
+            (Some('c'), None, vec![(0, 2), (1, 1)]),
+            "char_at should return 'c' for (0, 2) and neighbors_at should list (row, col) pairs"
+        );
+    }
+
+    #[test]
+    pub fn test_enumerate() {
+        // Given a 3x3 grid
+        let grid = setup_grid();
+
+        // When enumerate is collected
+        let actual: Vec<(usize, usize, &char)> = grid.enumerate().collect();
+
+        // Then it should yield every cell exactly once in row-major order
+        let expected: Vec<(usize, usize, &char)> = (0..3)
+            .flat_map(|y| (0..3).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, grid.get(x, y).unwrap()))
+            .collect();
+        assert_eq!(
+            actual, expected,
+            "enumerate should yield all 9 cells in row-major order"
+        );
+    }
+
+    mod test_boundary_edges {
+        use crate::{Grid, Input};
+
+        #[test]
+        pub fn checkerboard() {
+            // Given a 3x3 checkerboard grid
+            let grid = Grid::from_input(&Input::from_lines(&["#.#", ".#.", "#.#"]), |c| c);
+
+            // Then every inner pair and every outer side should be a boundary
+            assert_eq!(
+                grid.boundary_edges(),
+                24,
+                "boundary_edges should return 24 for a 3x3 checkerboard"
+            )
+        }
+
+        #[test]
+        pub fn uniform() {
+            // Given a 2x3 grid where every cell holds the same value
+            let grid = Grid::from_input(&Input::from_lines(&["aaa", "aaa"]), |c| c);
+
+            // Then only the sides on the edge of the grid should be boundaries
+            assert_eq!(
+                grid.boundary_edges(),
+                10,
+                "boundary_edges should return 10 for a uniform 3x2 grid"
+            )
+        }
+    }
+
+    mod test_transpose {
+        use crate::transpose;
+
+        fn lines(lines: &[&str]) -> Vec<String> {
+            lines.iter().map(|line| line.to_string()).collect()
+        }
+
+        #[test]
+        pub fn square_grid() {
+            // Then it should turn the rows of a square grid into its columns
+            assert_eq!(
+                transpose(&lines(&["ab", "cd"])),
+                vec!["ac", "bd"],
+                "transpose should return [\"ac\", \"bd\"] for [\"ab\", \"cd\"]"
+            )
+        }
+
+        #[test]
+        pub fn wide_grid() {
+            // Then it should return one line per column of a wider-than-tall grid
+            assert_eq!(
+                transpose(&lines(&["abc", "def"])),
+                vec!["ad", "be", "cf"],
+                "transpose should return [\"ad\", \"be\", \"cf\"] for [\"abc\", \"def\"]"
+            )
+        }
+
+        #[test]
+        pub fn ragged_input() {
+            // Then it should pad shorter rows with spaces
+            assert_eq!(
+                transpose(&lines(&["abc", "d", "ef"])),
+                vec!["ade", "b f", "c  "],
+                "transpose should return [\"ade\", \"b f\", \"c  \"] for [\"abc\", \"d\", \"ef\"]"
+            )
+        }
+    }
+
+    mod test_mirror {
+        use crate::{Grid, Input};
+
+        fn setup_pattern() -> Grid<char> {
+            Grid::from(&Input::from_lines(&[
+                "#.##..##.",
+                "..#.##.#.",
+                "##......#",
+                "##......#",
+                "..#.##.#.",
+                "..##..##.",
+                "#.#.##.#.",
+            ]))
+        }
+
+        #[test]
+        pub fn clean_mirror() {
+            // Given a pattern reflected across a vertical line
+            let grid = setup_pattern();
+
+            // Then it should find the vertical line and no horizontal line
+            assert_eq!(
+                (grid.vertical_mirror(0), grid.horizontal_mirror(0)),
+                (Some(5), None),
+                "the pattern should reflect after column 5 without smudges"
+            )
+        }
+
+        #[test]
+        pub fn smudged_mirror() {
+            // Given a pattern that reflects horizontally once a single cell is fixed
+            let grid = setup_pattern();
+
+            // Then it should find the horizontal line with one smudge
+            assert_eq!(
+                grid.horizontal_mirror(1),
+                Some(3),
+                "the pattern should reflect after row 3 with one smudge"
+            )
+        }
+    }
+
+    mod test_component_count {
+        use crate::{Grid, Input};
+
+        #[test]
+        pub fn distinct_regions() {
+            // Then it should count each of three letter regions once
+            assert_eq!(
+                Grid::from(&Input::from_lines(&["AAB", "ACB", "CCB"])).component_count(),
+                3,
+                "component_count should return 3 for three letter regions"
+            )
+        }
+
+        #[test]
+        pub fn split_region() {
+            // Then it should count separate areas of the same letter as separate regions
+            assert_eq!(
+                Grid::from(&Input::from_lines(&["AAB", "ACB", "CCA"])).component_count(),
+                4,
+                "component_count should return 4 when the 'A' cells form two areas"
+            )
+        }
+    }
+
+    mod test_spin_cycle {
+        use crate::{Grid, Input};
+
+        fn setup_platform() -> Grid<char> {
+            Grid::from(&Input::from_lines(&[
+                "O....#....",
+                "O.OO#....#",
+                ".....##...",
+                "OO.#O....O",
+                ".O.....O#.",
+                "O.#..O.#.#",
+                "..O..#O..O",
+                ".......O..",
+                "#....###..",
+                "#OO..#....",
+            ]))
+        }
+
+        #[test]
+        pub fn one_cycle() {
+            // Given the sample platform
+            let mut grid = setup_platform();
+
+            // When spin_cycle is called once
+            grid.spin_cycle();
+
+            // Then the rocks should be in the known state after one cycle
+            assert_eq!(
+                grid.to_input().lines(),
+                &vec![
+                    ".....#....",
+                    "....#...O#",
+                    "...OO##...",
+                    ".OO#......",
+                    ".....OOO#.",
+                    ".O#...O#.#",
+                    "....O#....",
+                    "......OOOO",
+                    "#...O###..",
+                    "#..OO#....",
+                ],
+                "spin_cycle should match the sample state after one cycle"
+            );
+        }
+
+        #[test]
+        pub fn total_load() {
+            // Then it should sum the distances of the rounded rocks to the south edge
+            assert_eq!(
+                setup_platform().total_load(),
+                104,
+                "total_load should return 104 for the untilted sample platform"
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_label_regions() {
+        // Given a grid with two regions
+        let grid = Grid::from(&Input::from_lines(&["AAB", "ABB"]));
+
+        // When label_regions is called
+        let actual = grid.label_regions();
+
+        // Then the cells of each region should share a distinct id
+        assert_eq!(
+            actual,
+            Grid::from_input(&Input::from_lines(&["001", "011"]), |c| c as usize
+                - '0' as usize),
+            "label_regions should label the 'A' region 0 and the 'B' region 1"
+        );
+    }
+
+    #[test]
+    pub fn test_energized_count() {
+        // Given the sample contraption of mirrors and splitters
+        let grid = Grid::from(&Input::from_lines(&[
+            r".|...\....",
+            r"|.-.\.....",
+            r".....|-...",
+            r"........|.",
+            r"..........",
+            r".........\",
+            r"..../.\\..",
+            r".-.-/..|..",
+            r".|....-|.\",
+            r"..//.|....",
+        ]));
+
+        // When energized_count is called for a beam entering the top-left cell heading right
+        let actual = grid.energized_count(((0, 0), Direction::Right));
+
+        // Then it should return the number of energized cells
+        assert_eq!(
+            actual, 46,
+            "energized_count should return 46 for the sample contraption"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+/// Splits a sequence into runs of equal adjacent elements.
+///
+/// # Arguments
+///
+/// * `items` - A slice of elements to group.
+///
+/// # Returns
+///
+/// Returns a `Vec` of runs, each containing one or more consecutive equal elements.
+/// An empty slice yields no runs.
+pub fn group_consecutive<T: PartialEq + Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    for item in items {
+        match groups.last_mut() {
+            Some(group) if group[0] == *item => group.push(item.clone()),
+            _ => groups.push(vec![item.clone()]),
+        }
+    }
+    groups
+}
+
+/// Repeatedly applies a step function until the state stops changing.
+///
+/// # Arguments
+///
+/// * `initial` - The state to start the simulation from.
+/// * `step` - A function that computes the next state from the current one.
+///
+/// # Returns
+///
+/// Returns a tuple of the stable state and the number of steps that changed the state.
+/// Never returns if the simulation does not converge; use `fixpoint_capped` to guard
+/// against that.
+pub fn fixpoint<S: PartialEq + Clone>(initial: S, step: impl Fn(&S) -> S) -> (S, usize) {
+    let mut state = initial;
+    let mut iterations = 0;
+    loop {
+        let next = step(&state);
+        if next == state {
+            return (state, iterations);
+        }
+        state = next;
+        iterations += 1;
+    }
+}
+
+/// Repeatedly applies a step function until the state stops changing or a cap is reached.
+///
+/// # Arguments
+///
+/// * `initial` - The state to start the simulation from.
+/// * `step` - A function that computes the next state from the current one.
+/// * `max_iterations` - The maximum number of state-changing steps to allow.
+///
+/// # Returns
+///
+/// An `Option` containing the stable state and the number of steps that changed the
+/// state, or `None` if the state was still changing after `max_iterations` steps.
+pub fn fixpoint_capped<S: PartialEq + Clone>(
+    initial: S,
+    step: impl Fn(&S) -> S,
+    max_iterations: usize,
+) -> Option<(S, usize)> {
+    let mut state = initial;
+    for iterations in 0..=max_iterations {
+        let next = step(&state);
+        if next == state {
+            return Some((state, iterations));
+        }
+        state = next;
+    }
+    None
+}
+
+/// Finds both the smallest and the largest element in a single pass.
+///
+/// # Arguments
+///
+/// * `items` - A slice of elements to search.
+///
+/// # Returns
+///
+/// An `Option` containing `(min, max)`, or `None` if the slice is empty.
+pub fn min_max<T: Ord + Copy>(items: &[T]) -> Option<(T, T)> {
+    let (&first, rest) = items.split_first()?;
+    Some(rest.iter().fold((first, first), |(min, max), &item| {
+        (min.min(item), max.max(item))
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_group_consecutive() {
+        // Given a sequence containing runs of equal elements
+        let items = [1, 1, 2, 3, 3, 3];
+
+        // When group_consecutive is called
+        let actual = group_consecutive(&items);
+
+        // Then it should return one group per run
+        assert_eq!(
+            actual,
+            vec![vec![1, 1], vec![2], vec![3, 3, 3]],
+            "group_consecutive should return [[1, 1], [2], [3, 3, 3]] \
+               for an input of [1, 1, 2, 3, 3, 3]"
+        );
+    }
+
+    #[test]
+    pub fn test_fixpoint() {
+        // Given a step that halves a number, which settles at zero
+        let step = |n: &u32| n / 2;
+
+        // When fixpoint is called starting from 10
+        let actual = fixpoint(10, step);
+
+        // Then it should return zero after four changing steps (10, 5, 2, 1, 0)
+        assert_eq!(
+            actual,
+            (0, 4),
+            "fixpoint should return (0, 4) when halving from 10"
+        );
+    }
+
+    mod test_fixpoint_capped {
+        use crate::iter::fixpoint_capped;
+
+        #[test]
+        pub fn converges_within_cap() {
+            // Then it should return the stable state when it converges within the cap
+            assert_eq!(
+                fixpoint_capped(10, |n: &u32| n / 2, 4),
+                Some((0, 4)),
+                "fixpoint_capped should return Some((0, 4)) when halving from 10"
+            )
+        }
+
+        #[test]
+        pub fn exceeds_cap() {
+            // Then it should return None when the state keeps changing past the cap
+            assert_eq!(
+                fixpoint_capped(0, |n: &u32| n + 1, 100),
+                None,
+                "fixpoint_capped should return None for a state that never settles"
+            )
+        }
+    }
+
+    mod test_min_max {
+        use crate::min_max;
+
+        #[test]
+        pub fn several_items() {
+            // Then it should return the smallest and largest elements
+            assert_eq!(
+                min_max(&[3, -7, 12, 0, 5]),
+                Some((-7, 12)),
+                "min_max should return Some((-7, 12)) for [3, -7, 12, 0, 5]"
+            )
+        }
+
+        #[test]
+        pub fn empty_slice() {
+            // Then it should return None for an empty slice
+            assert_eq!(
+                min_max::<i32>(&[]),
+                None,
+                "min_max should return None for an empty slice"
+            )
+        }
+    }
+}
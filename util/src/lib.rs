@@ -1,5 +1,41 @@
-use std::fs::File;
+use anyhow::Error;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::Instant;
+
+pub mod almanac;
+mod cards;
+mod direction;
+mod error;
+mod graph;
+mod grid;
+mod iter;
+mod mapped;
+mod math;
+mod options;
+mod parse;
+pub mod pulse;
+
+pub use cards::{compare_by_order, hand_type};
+pub use direction::{char_to_direction, direction_to_arrow, Direction};
+pub use error::InputError;
+pub use graph::{max_clique, parse_graph};
+pub use grid::{transpose, Grid};
+pub use iter::{fixpoint, fixpoint_capped, group_consecutive, min_max};
+pub use mapped::MappedInput;
+pub use math::{aoc_hash, extrapolate_quadratic, region_sum, PrefixSums};
+pub use options::{EmptyLines, LoadOptions, Trim};
+pub use parse::{
+    common_prefix, extract_ints, extract_uints, parse_game, split_digits, split_on, CubeSet,
+};
 
 /// Represents input data loaded from a file, stored as lines.
 ///
@@ -7,26 +43,290 @@ use std::io::{self, BufRead, BufReader, Read, Write};
 /// of the file is stored as a separate string in a vector.
 pub struct Input {
     lines: Vec<String>,
+    raw: Option<String>,
+}
+
+/// Summary statistics about the lengths of the lines of an `Input`.
+///
+/// Lengths are measured in characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineStats {
+    /// The number of lines.
+    pub count: usize,
+    /// The total number of characters over all lines.
+    pub total_chars: usize,
+    /// The length of the longest line, or 0 for an empty input.
+    pub max_len: usize,
+    /// The average line length, or 0.0 for an empty input.
+    pub average_len: f64,
 }
 
 impl Input {
     /// Loads input from a file located at the specified path.
     ///
     /// This function reads the file line by line and stores each line
-    /// as a string in a vector.
+    /// as a string in a vector, in the same way as `from_reader`.
     ///
     /// # Arguments
     ///
-    /// * `file_path` - A string slice that holds the path to the file.
+    /// * `file_path` - The path to the file, such as a `&str` or a `PathBuf`.
     ///
     /// # Returns
     ///
     /// Returns `Input` instance containing the lines of the file, or an `io::Error`.
-    pub fn load(file_path: &str) -> io::Result<Self> {
+    pub fn load(file_path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(file_path)?;
+        Self::from_reader(BufReader::new(file))
+    }
+
+    /// Loads the puzzle input of a day, wherever the binary is run from.
+    ///
+    /// The input is looked up in the following places, and the first existing file
+    /// is loaded:
+    ///
+    /// 1. `{AOC_INPUT_DIR}/{day}/input`, when the `AOC_INPUT_DIR` environment variable
+    ///    is set.
+    /// 2. `./{day}/input`, when run from the workspace root.
+    /// 3. `./input`, when run from the day's own directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `day` - A string slice naming the day's directory, such as `"day01"`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines of the input file, or an
+    /// `io::Error` listing the places looked in if none of them exists.
+    pub fn for_day(day: &str) -> io::Result<Self> {
+        let input_dir = env::var_os("AOC_INPUT_DIR");
+        let candidates = input_candidates(input_dir.as_deref().map(Path::new), Path::new("."), day);
+        Self::load(find_existing(&candidates)?)
+    }
+
+    /// Loads an input file, downloading it from adventofcode.com first if it is missing.
+    ///
+    /// The download uses the value of the `session` cookie from the `AOC_SESSION`
+    /// environment variable, the same one `start-day.sh` reads from `.env`. An existing
+    /// file is always loaded as is and never downloaded again, so the puzzle server is
+    /// contacted at most once per input. A download is first written to a temporary
+    /// file next to `path` and only moved into place once it is complete, so a failed
+    /// or empty download never ends up cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path the input is loaded from and saved to, such as `day01/input`.
+    /// * `day` - The day of the 2023 puzzle the input belongs to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines of the input file, or an
+    /// `io::Error` if the session is not set, the download fails, or the file can not
+    /// be read or written.
+    pub fn load_or_fetch(path: impl AsRef<Path>, day: u32) -> io::Result<Self> {
+        let path = path.as_ref();
+        Self::load_or_else(path, || {
+            let session = env::var("AOC_SESSION").map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} is missing and AOC_SESSION is not set", path.display()),
+                )
+            })?;
+            fetch_input(day, &session)
+        })
+    }
+
+    /// Loads an input file, saving the text returned by `fetch` to it first if it is missing.
+    fn load_or_else(path: &Path, fetch: impl FnOnce() -> io::Result<String>) -> io::Result<Self> {
+        if path.exists() && !path.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} exists but is not a file", path.display()),
+            ));
+        }
+        if !path.is_file() {
+            let data = fetch()?;
+            if data.trim().is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("refusing to save an empty input to {}", path.display()),
+                ));
+            }
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let partial = path.with_file_name(format!(".{file_name}.partial"));
+            write_file_p(&partial, &data)
+                .and_then(|()| fs::rename(&partial, path))
+                .inspect_err(|_| {
+                    let _ = fs::remove_file(&partial);
+                })?;
+        }
+        Self::load(path)
+    }
+
+    /// Reads input line by line from any buffered reader.
+    ///
+    /// Both LF and CRLF line endings are accepted, and a final line ending does not
+    /// produce an extra empty line. A UTF-8 byte order mark at the start of the input
+    /// is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A buffered reader providing the input, such as a file or a `Cursor`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines read, or an `io::Error`.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        Self::read_with_progress(reader, |_| {})
+    }
+
+    /// Reads input line by line from standard input, such as input piped into a day.
+    ///
+    /// The lines are normalized as in `from_reader`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines read, or an `io::Error`.
+    pub fn from_stdin() -> io::Result<Self> {
+        Self::from_reader(io::stdin().lock())
+    }
+
+    /// Loads input from a specified file, reporting each line as it is read.
+    ///
+    /// The lines are normalized exactly as in `load`, so the result is the same.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - A string slice that holds the path to the input file.
+    /// * `on_line` - A function called with the zero-based number of each line read.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines read from the file, or an `io::Error`.
+    pub fn load_with_progress(file_path: &str, on_line: impl FnMut(usize)) -> io::Result<Self> {
+        let file = File::open(file_path)?;
+        Self::read_with_progress(BufReader::new(file), on_line)
+    }
+
+    /// Loads input from a specified file, removing trailing whitespace from each line.
+    ///
+    /// Leading whitespace is kept, so grids that rely on indentation are preserved.
+    /// Use `load` instead when trailing whitespace is significant.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - A string slice that holds the path to the input file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the trimmed lines of the file, or an `io::Error`.
+    pub fn load_trimmed(file_path: &str) -> io::Result<Self> {
+        let mut input = Self::load(file_path)?;
+        for line in &mut input.lines {
+            line.truncate(line.trim_end().len());
+        }
+        Ok(input)
+    }
+
+    /// Loads input from a specified file, replacing invalid UTF-8 instead of failing.
+    ///
+    /// Each invalid byte sequence is replaced with the replacement character `�`,
+    /// leaving the rest of its line intact. The lines are otherwise normalized as in
+    /// `load`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - A string slice that holds the path to the input file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines of the file, or an `io::Error` if
+    /// the file cannot be read.
+    pub fn load_lossy(file_path: &str) -> io::Result<Self> {
+        let bytes = fs::read(file_path)?;
+        Self::from_reader(String::from_utf8_lossy(&bytes).as_bytes())
+    }
+
+    /// Loads input from a specified file, normalizing the lines as configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, such as a `&str` or a `PathBuf`.
+    /// * `options` - The `LoadOptions` to apply. `LoadOptions::default()` behaves like
+    ///   `load`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the normalized lines of the file, or an
+    /// `io::Error`.
+    pub fn load_with(file_path: impl AsRef<Path>, options: LoadOptions) -> io::Result<Self> {
+        let file = File::open(file_path)?;
+        let mut lines = Self::normalized_lines(BufReader::new(file), options.strip_cr)
+            .map(|line| line.map(|line| options.trim.apply(line)))
+            .collect::<io::Result<Vec<_>>>()?;
+        match options.empty_lines {
+            EmptyLines::Keep => {}
+            EmptyLines::Skip => lines.retain(|line| !line.is_empty()),
+            EmptyLines::SkipTrailing => {
+                while lines.last().is_some_and(|line| line.is_empty()) {
+                    lines.pop();
+                }
+            }
+        }
+        Ok(Self { lines, raw: None })
+    }
+
+    /// Reads and normalizes lines from a buffered reader, reporting each line as it is read.
+    fn read_with_progress<R: BufRead>(
+        reader: R,
+        mut on_line: impl FnMut(usize),
+    ) -> io::Result<Self> {
+        let mut lines = Vec::new();
+        for (line_number, line) in Self::normalized_lines(reader, true).enumerate() {
+            lines.push(line?);
+            on_line(line_number);
+        }
+        Ok(Self { lines, raw: None })
+    }
+
+    /// Streams the lines of a specified file without holding the whole file in memory.
+    ///
+    /// The lines are normalized exactly as in `load`, so collecting the stream gives
+    /// the same lines as loading the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, such as a `&str` or a `PathBuf`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding each line or the `io::Error` of reading it, or an
+    /// `io::Error` if the file cannot be opened.
+    pub fn stream(
+        file_path: impl AsRef<Path>,
+    ) -> io::Result<impl Iterator<Item = io::Result<String>>> {
         let file = File::open(file_path)?;
-        let reader = BufReader::new(file);
-        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        Ok(Self { lines })
+        Ok(Self::normalized_lines(BufReader::new(file), true))
+    }
+
+    /// Lazily reads lines from a buffered reader, removing a byte order mark from the
+    /// first line and, if `strip_cr` is set, a trailing `\r` from every line.
+    fn normalized_lines<R: BufRead>(
+        reader: R,
+        strip_cr: bool,
+    ) -> impl Iterator<Item = io::Result<String>> {
+        reader.split(b'\n').enumerate().map(move |(index, line)| {
+            let mut line = String::from_utf8(line?)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            if strip_cr && line.ends_with('\r') {
+                line.pop();
+            }
+            if index == 0 {
+                if let Some(stripped) = line.strip_prefix('\u{FEFF}') {
+                    line = stripped.to_string();
+                }
+            }
+            Ok(line)
+        })
     }
 
     /// Creates an `Input` instance from an array of lines.
@@ -41,6 +341,23 @@ impl Input {
     pub fn from_lines(lines: &[&str]) -> Self {
         Self {
             lines: lines.iter().map(|&line| line.to_string()).collect(),
+            raw: None,
+        }
+    }
+
+    /// Creates an `Input` instance from a grid of characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - A slice of rows, each containing the characters of one line.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance with one line per row. Empty rows produce empty lines.
+    pub fn from_grid(grid: &[Vec<char>]) -> Input {
+        Self {
+            lines: grid.iter().map(|row| row.iter().collect()).collect(),
+            raw: None,
         }
     }
 
@@ -52,130 +369,2874 @@ impl Input {
     pub fn lines(&self) -> &Vec<String> {
         &self.lines
     }
-}
-
-/// Writes data to a specified file.
-///
-/// # Arguments
-///
-/// * `file_path` - A string slice that holds the path to the file.
-/// * `data` - A string slice that holds the data to be written.
-///
-/// # Returns
-///
-/// The functions returns an `io::Result<()>`. On success, it returns `Ok(())`,
-/// and on failure, it returns an `io::Error`.
-pub fn write_file(file_path: &str, data: &str) -> io::Result<()> {
-    let mut file = File::create(file_path)?;
-    file.write_all(data.as_bytes())
-}
-
-/// Reads and returns the contents of a specified file.
-///
-/// # Arguments
-///
-/// * `file_path` - A string slice that holds the path to the file.
-///
-/// # Returns
-///
-/// The functions returns an `io::Result<(String)>`. On success, it returns `Ok(String)`
-/// containing the file contents, and on failure, it returns an `io::Error`.
-pub fn read_file(file_path: &str) -> io::Result<String> {
-    let mut file = File::open(file_path)?;
-    let mut content: String = String::new();
-    file.read_to_string(&mut content)?;
-    Ok(content)
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use anyhow::Result;
-    use tempfile::{tempdir, TempDir};
+    /// Provides the text the input was created from.
+    ///
+    /// An `Input` parsed from a string with `str::parse` keeps that string exactly,
+    /// including a trailing line ending and any `\r` characters. For any other `Input`,
+    /// or after the lines have been changed in place, the text is rebuilt by joining
+    /// the lines with `\n`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the text of the input, borrowed when the original text is available.
+    pub fn raw(&self) -> Cow<'_, str> {
+        match &self.raw {
+            Some(raw) => Cow::Borrowed(raw),
+            None => Cow::Owned(self.lines.join("\n")),
+        }
+    }
 
-    // Helper function that creates a temp directory and file
-    fn setup_temp_file(file_name: &str) -> io::Result<(TempDir, String)> {
-        let temp_dir: TempDir = tempdir()?;
-        let file_path_buf = temp_dir.path().join(file_name);
-        let file_path: String = file_path_buf.to_str().unwrap().to_string();
-        Ok((temp_dir, file_path))
+    /// Iterates over the lines of the input as string slices.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding each line as a `&str`, in input order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
     }
 
-    // Helper function that creates a temp directory and file with content
-    fn setup_temp_file_with_content(
-        file_name: &str,
-        content: &str,
-    ) -> io::Result<(TempDir, String)> {
-        let (temp_dir, file_path) = setup_temp_file(file_name)?;
-        let mut file = File::create(&file_path)?;
-        file.write_all(content.as_bytes())?;
-        Ok((temp_dir, file_path))
+    /// Returns the number of lines in the input.
+    pub fn len(&self) -> usize {
+        self.lines.len()
     }
 
-    mod test_input {
-        use crate::test::setup_temp_file_with_content;
-        use crate::Input;
+    /// Returns `true` if the input contains no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
 
-        #[test]
-        pub fn test_load() -> anyhow::Result<()> {
-            // Given a path to a file that contains multiple lines
-            let file_contents = "Line 1\nLine 2\nLine 3";
-            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+    /// Reverses the order of the lines stored in the Input struct in place.
+    ///
+    /// The contents of each line are left untouched, only their order changes.
+    pub fn reverse_in_place(&mut self) {
+        self.raw = None;
+        self.lines.reverse();
+    }
 
-            // When Input is loaded from this file
-            let input = Input::load(&file_path)?;
+    /// Extracts every signed integer from the input, ignoring line boundaries.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<i64>` containing all integers of the input in the order they appear.
+    pub fn ints(&self) -> Vec<i64> {
+        extract_ints(&self.lines.join("\n"))
+    }
 
-            // Then it should contain each line of the file in its lines vector
-            assert_eq!(
-                input.lines,
-                vec!["Line 1", "Line 2", "Line 3"],
-                "Input should contain [\"Line 1\", \"Line 2\", \"Line 3\"] \
-                   in its lines vector"
-            );
-            drop(temp_dir);
-            Ok(())
+    /// Provides a borrowed byte view of the input as a grid of rows.
+    ///
+    /// This avoids copying the input for grid-heavy puzzles, but is only possible
+    /// when the input is ASCII and every line has the same length, so that each byte
+    /// corresponds to exactly one cell.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of byte slices borrowed from the lines, or an `InputError` if
+    /// the input contains non-ASCII characters or lines of differing lengths.
+    pub fn grid_view(&self) -> Result<Vec<&[u8]>, InputError> {
+        if let Some(index) = self.lines.iter().position(|line| !line.is_ascii()) {
+            return Err(InputError::NonAscii { line_no: index + 1 });
         }
+        self.ensure_rectangular(str::len)?;
+        Ok(self.lines.iter().map(|line| line.as_bytes()).collect())
+    }
 
-        #[test]
-        pub fn test_from_lines() -> anyhow::Result<()> {
-            // Given an array of lines
-            let lines = ["Line 1", "Line 2", "Line 3"];
+    /// Builds an owned character grid from the input, with one row per line.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<Vec<char>>` of rows, or an `InputError` naming the first line
+    /// whose number of characters differs from the first line.
+    pub fn as_grid(&self) -> Result<Vec<Vec<char>>, InputError> {
+        self.ensure_rectangular(|line| line.chars().count())?;
+        Ok(self.char_rows())
+    }
 
-            // When Input is created from these lines
-            let input = Input::from_lines(&lines);
+    /// Builds an owned byte grid from the input, with one row per line.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<Vec<u8>>` of rows, or an `InputError` naming the first line
+    /// whose number of bytes differs from the first line.
+    pub fn as_byte_grid(&self) -> Result<Vec<Vec<u8>>, InputError> {
+        self.ensure_rectangular(str::len)?;
+        Ok(self
+            .lines
+            .iter()
+            .map(|line| line.as_bytes().to_vec())
+            .collect())
+    }
 
-            // Then it should contain the lines
-            assert_eq!(
-                input.lines(),
-                &vec!["Line 1", "Line 2", "Line 3"],
-                "Input.lines() should return [\"Line 1\", \"Line 2\", \"Line 3\"]"
-            );
-            Ok(())
+    /// Checks whether every line of the input has the same number of characters.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if all lines share the same length, which is trivially the case
+    /// for an empty input, and `false` otherwise.
+    pub fn is_rectangular(&self) -> bool {
+        let mut lengths = self.lines.iter().map(|line| line.chars().count());
+        match lengths.next() {
+            Some(width) => lengths.all(|length| length == width),
+            None => true,
         }
     }
 
-    #[test]
-    pub fn test_write_file() -> Result<()> {
-        // Given a file path and content data
-        let file_contents: &str = "test data";
-        let (temp_dir, file_path) = setup_temp_file("test.txt")?;
+    /// Returns every top-left to bottom-right diagonal of the input grid.
+    ///
+    /// The diagonals are ordered from the bottom-left corner to the top-right corner,
+    /// and each one is read from its top-left end. The input is expected to be
+    /// rectangular; positions missing from shorter lines are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<String>` containing one string per diagonal.
+    pub fn diagonals(&self) -> Vec<String> {
+        let grid = self.char_rows();
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        (0..(width + height).saturating_sub(1))
+            .map(|diagonal| {
+                (0..height)
+                    .filter_map(|y| grid[y].get((diagonal + y).checked_sub(height - 1)?))
+                    .collect()
+            })
+            .collect()
+    }
 
-        // When write_file is called
-        write_file(&file_path, file_contents)?;
+    /// Returns every top-right to bottom-left diagonal of the input grid.
+    ///
+    /// The anti-diagonals are ordered from the top-left corner to the bottom-right corner,
+    /// and each one is read from its top-right end. The input is expected to be
+    /// rectangular; positions missing from shorter lines are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<String>` containing one string per anti-diagonal.
+    pub fn anti_diagonals(&self) -> Vec<String> {
+        let grid = self.char_rows();
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        (0..(width + height).saturating_sub(1))
+            .map(|diagonal| {
+                (0..height)
+                    .filter_map(|y| grid[y].get(diagonal.checked_sub(y)?))
+                    .collect()
+            })
+            .collect()
+    }
 
-        // Then it writes the data to the specified file path
-        let mut file = File::open(file_path)?;
-        let mut actual: String = String::new();
-        file.read_to_string(&mut actual)?;
+    /// Returns the blocks of consecutive non-empty lines, without the empty separators.
+    fn block_slices(&self) -> impl Iterator<Item = &[String]> {
+        self.lines
+            .split(|line| line.is_empty())
+            .filter(|block| !block.is_empty())
+    }
 
-        assert_eq!(
-            actual, file_contents,
-            "write_file should write \"${file_contents}\" to specified file."
-        );
-        drop(temp_dir);
+    /// Checks that every line has the same length as the first, as measured by `length`.
+    fn ensure_rectangular(&self, length: impl Fn(&str) -> usize) -> Result<(), InputError> {
+        let expected = self.lines.first().map_or(0, |line| length(line));
+        for (index, line) in self.lines.iter().enumerate() {
+            let len = length(line);
+            if len != expected {
+                return Err(InputError::RaggedGrid {
+                    line_no: index + 1,
+                    len,
+                    expected,
+                });
+            }
+        }
         Ok(())
     }
 
+    /// Parses the whitespace-separated integers of a line at a zero-based index.
+    fn line_ints(index: usize, line: &str) -> Result<Vec<i64>, InputError> {
+        line.split_whitespace()
+            .map(|value| {
+                value
+                    .parse::<i64>()
+                    .map_err(|error| InputError::parse(index, line, error))
+            })
+            .collect()
+    }
+
+    /// Collects the characters of each line into a row of the grid.
+    fn char_rows(&self) -> Vec<Vec<char>> {
+        self.lines
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+
+    /// Returns the lines matching a predicate together with their indexes.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - A function that decides whether a line should be included.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of `(line_index, line)` tuples for the matching lines, in input order.
+    pub fn enumerate_matching(&self, pred: impl Fn(&str) -> bool) -> Vec<(usize, &String)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| pred(line))
+            .collect()
+    }
+
+    /// Finds the line with the greatest key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A function that computes the key to compare lines by.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the first line with the greatest key, or `None` if the
+    /// input is empty.
+    pub fn max_line_by<K: Ord>(&self, key: impl Fn(&str) -> K) -> Option<&String> {
+        self.lines.iter().rev().max_by_key(|line| key(line))
+    }
+
+    /// Finds the line with the smallest key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A function that computes the key to compare lines by.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the first line with the smallest key, or `None` if the
+    /// input is empty.
+    pub fn min_line_by<K: Ord>(&self, key: impl Fn(&str) -> K) -> Option<&String> {
+        self.lines.iter().min_by_key(|line| key(line))
+    }
+
+    /// Collects the columns of the input, from left to right.
+    ///
+    /// Each column is read from top to bottom, and lines shorter than the longest
+    /// line are padded with spaces, as with `columns_iter`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<String>` with one string per column.
+    pub fn columns(&self) -> Vec<String> {
+        transpose(&self.lines)
+    }
+
+    /// Turns the input on its side, so its columns become lines.
+    ///
+    /// Ragged input is padded with spaces first, so transposing twice restores the
+    /// original lines padded to the same length.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Input` whose lines are the columns of this one.
+    pub fn transposed(&self) -> Input {
+        Input {
+            lines: self.columns(),
+            raw: None,
+        }
+    }
+
+    /// Lazily yields the columns of the input, from left to right.
+    ///
+    /// Each column is read from top to bottom. Lines shorter than the longest line
+    /// are padded with spaces so that every column has one character per line.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding one `String` per column.
+    pub fn columns_iter(&self) -> impl Iterator<Item = String> + '_ {
+        grid::transpose_iter(&self.lines)
+    }
+
+    /// Loads input from several files and concatenates their lines in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - A slice of paths to the files to load.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Input` instance containing the lines of all files, or the `io::Error`
+    /// of the first file that could not be loaded.
+    pub fn load_concat(paths: &[&str]) -> io::Result<Input> {
+        let mut lines = Vec::new();
+        for path in paths {
+            lines.extend(Self::load(path)?.lines);
+        }
+        Ok(Self { lines, raw: None })
+    }
+
+    /// Splits each line into tokens separated by any of several delimiter characters.
+    ///
+    /// Each token is trimmed, and empty tokens, such as those between two adjacent
+    /// delimiters, are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `delims` - The characters that separate tokens.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` containing the tokens of each line.
+    pub fn tokenize(&self, delims: &[char]) -> Vec<Vec<String>> {
+        self.lines
+            .iter()
+            .map(|line| {
+                line.split(delims)
+                    .map(str::trim)
+                    .filter(|token| !token.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Sums each column of a table of whitespace-separated integers.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<i64>` with the sum of each column, or an `InputError` if a value
+    /// is not an integer or if the lines do not all have the same number of columns.
+    pub fn column_sums(&self) -> Result<Vec<i64>, InputError> {
+        let mut sums: Option<Vec<i64>> = None;
+        for (index, line) in self.lines.iter().enumerate() {
+            let values = Self::line_ints(index, line)?;
+            match sums.as_mut() {
+                None => sums = Some(values),
+                Some(sums) if sums.len() == values.len() => sums
+                    .iter_mut()
+                    .zip(values)
+                    .for_each(|(sum, value)| *sum += value),
+                Some(sums) => {
+                    return Err(InputError::ValueCount {
+                        line_no: index + 1,
+                        count: values.len(),
+                        expected: sums.len(),
+                    })
+                }
+            }
+        }
+        Ok(sums.unwrap_or_default())
+    }
+
+    /// Splits the input into two at a line index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the first line of the second part. Indexes past the
+    ///   end are clamped to the number of lines.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of two new `Input` instances, holding the lines `[0, index)` and
+    /// `[index, end)` respectively.
+    pub fn split_at(&self, index: usize) -> (Input, Input) {
+        let (head, tail) = self.lines.split_at(index.min(self.lines.len()));
+        (
+            Self {
+                lines: head.to_vec(),
+                raw: None,
+            },
+            Self {
+                lines: tail.to_vec(),
+                raw: None,
+            },
+        )
+    }
+
+    /// Yields overlapping bands of `n` consecutive rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of rows in each band.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding each band as a `Vec` of line references. Nothing is
+    /// yielded when `n` is larger than the number of rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn row_bands(&self, n: usize) -> impl Iterator<Item = Vec<&String>> + '_ {
+        self.windows(n).map(|band| band.iter().collect())
+    }
+
+    /// Yields overlapping slices of `size` consecutive lines, like `slice::windows`.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of lines in each window.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding each window as a slice of lines. Nothing is
+    /// yielded when `size` is larger than the number of lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = &[String]> {
+        self.lines.windows(size)
+    }
+
+    /// Splits the input into blocks of consecutive non-empty lines.
+    ///
+    /// Blocks are separated by one or more empty lines, which are discarded. Empty
+    /// lines at the start or end of the input do not form blocks of their own.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of blocks, each holding its lines in order. An input without
+    /// empty lines forms a single block.
+    pub fn blocks(&self) -> Vec<Vec<&str>> {
+        self.block_slices()
+            .map(|block| block.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    /// Parses each block of consecutive non-empty lines into a value.
+    ///
+    /// Blocks are split as in `blocks`, and the blocks are parsed in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function that parses the lines of a single block.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` with the parsed value of every block, or the first error
+    /// returned by `f`.
+    pub fn parse_blocks<T>(
+        &self,
+        f: impl Fn(&[String]) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.block_slices().map(f).collect()
+    }
+
+    /// Counts the blocks of consecutive non-empty lines in the input.
+    ///
+    /// Blocks are separated by one or more empty lines, and empty lines at the
+    /// start or end of the input do not form blocks of their own.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of blocks, which is 0 for an input without non-empty lines.
+    pub fn block_count(&self) -> usize {
+        self.block_slices().count()
+    }
+
+    /// Collects the positions of every character of the input grid.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `HashMap` from each character to its `(row, col)` positions, listed in
+    /// row-major order.
+    pub fn char_positions(&self) -> HashMap<char, Vec<(usize, usize)>> {
+        let mut positions: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
+        for (row, line) in self.lines.iter().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                positions.entry(c).or_default().push((row, col));
+            }
+        }
+        positions
+    }
+
+    /// Parses each non-empty line as a pair of whitespace-separated integers.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of `(i64, i64)` pairs, or an `InputError` if a line does not
+    /// consist of exactly two integers.
+    pub fn int_pairs(&self) -> Result<Vec<(i64, i64)>, InputError> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let values = Self::line_ints(index, line)?;
+                match values[..] {
+                    [a, b] => Ok((a, b)),
+                    _ => Err(InputError::ValueCount {
+                        line_no: index + 1,
+                        count: values.len(),
+                        expected: 2,
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Combines two inputs by alternating their lines.
+    ///
+    /// Lines are taken from `self` and `other` in turn until both are exhausted,
+    /// so the remaining lines of the longer input are appended at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The input whose lines follow each line of `self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Input` containing the interleaved lines.
+    pub fn interleave(&self, other: &Input) -> Input {
+        let length = self.lines.len().max(other.lines.len());
+        let lines = (0..length)
+            .flat_map(|index| [self.lines.get(index), other.lines.get(index)])
+            .flatten()
+            .cloned()
+            .collect();
+        Self { lines, raw: None }
+    }
+
+    /// Replaces the lines matching a predicate, leaving the other lines unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - A function that decides whether a line should be replaced.
+    /// * `replacement` - A function that computes the replacement for a matching line.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `Input` with the matching lines replaced.
+    pub fn replace_lines_where(
+        &self,
+        pred: impl Fn(&str) -> bool,
+        replacement: impl Fn(&str) -> String,
+    ) -> Input {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| {
+                if pred(line) {
+                    replacement(line)
+                } else {
+                    line.clone()
+                }
+            })
+            .collect();
+        Self { lines, raw: None }
+    }
+
+    /// Counts the characters of all lines, excluding line endings.
+    ///
+    /// # Returns
+    ///
+    /// Returns the sum of the line lengths in characters.
+    pub fn total_chars(&self) -> usize {
+        self.lines.iter().map(|line| line.chars().count()).sum()
+    }
+
+    /// Computes summary statistics about the lengths of the lines.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `LineStats` with the line count, the total number of characters,
+    /// and the longest and average line lengths.
+    pub fn line_stats(&self) -> LineStats {
+        let total_chars = self.total_chars();
+        let count = self.lines.len();
+        LineStats {
+            count,
+            total_chars,
+            max_len: self
+                .lines
+                .iter()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0),
+            average_len: if count == 0 {
+                0.0
+            } else {
+                total_chars as f64 / count as f64
+            },
+        }
+    }
+
+    /// Finds the rows that match a pattern, where `?` matches any character.
+    ///
+    /// A row matches when it has as many characters as the pattern and every
+    /// character equals the one at the same position in the pattern, unless the
+    /// pattern holds a `?` there.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - A string slice such as `"?#?"`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of the zero-based indexes of the matching rows, in order.
+    pub fn rows_matching(&self, pattern: &str) -> Vec<usize> {
+        let length = pattern.chars().count();
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| {
+                line.chars().count() == length
+                    && line
+                        .chars()
+                        .zip(pattern.chars())
+                        .all(|(c, p)| p == '?' || c == p)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Parses every line into a value of type `T`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<T>` with one value per line, or an `InputError` naming the number
+    /// and content of the first line that could not be parsed.
+    pub fn parse_lines<T>(&self) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(|error| InputError::parse(index, line, error))
+            })
+            .collect()
+    }
+
+    /// Splits every line on a delimiter and parses the pieces into typed values.
+    ///
+    /// The pieces are trimmed before parsing, as with `split_on`.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - The string separating the values of a line, such as `";"`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the parsed values of each line, or an
+    /// `InputError` naming the first line with a piece that could not be parsed.
+    pub fn split_lines_on<T>(&self, delimiter: &str) -> Result<Vec<Vec<T>>, InputError>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                split_on(line, delimiter).map_err(|error| InputError::parse(index, line, error))
+            })
+            .collect()
+    }
+
+    /// Treats the whole input as a single comma-separated sequence.
+    ///
+    /// Line breaks are ignored, so a long sequence wrapped over several lines is read
+    /// as one. Empty pieces, such as the one after a trailing comma, are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<String>` containing the steps of the sequence in order.
+    pub fn split_commas(&self) -> Vec<String> {
+        self.lines
+            .concat()
+            .split(',')
+            .filter(|step| !step.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Pairs the characters of this input with those at the same positions in another.
+    ///
+    /// Rows are compared up to the shorter of the two inputs, and each row up to the
+    /// shorter of the two lines, so positions present in only one input are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The `Input` to compare against.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding the `(row, col)` position together with the
+    /// character of this input and of the other input, in row-major order.
+    pub fn zip_cells<'a>(
+        &'a self,
+        other: &'a Input,
+    ) -> impl Iterator<Item = ((usize, usize), char, char)> + 'a {
+        self.lines
+            .iter()
+            .zip(&other.lines)
+            .enumerate()
+            .flat_map(|(row, (a, b))| {
+                a.chars()
+                    .zip(b.chars())
+                    .enumerate()
+                    .map(move |(col, (a, b))| ((row, col), a, b))
+            })
+    }
+
+    /// Writes the input to a file, with its lines joined by `\n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A string slice that holds the path to the file.
+    ///
+    /// # Returns
+    ///
+    /// The functions returns an `io::Result<()>`. On success, it returns `Ok(())`,
+    /// and on failure, it returns an `io::Error`.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        write_file(path, &self.to_string())
+    }
+
+    /// Parses the numbers on the first line of the input.
+    ///
+    /// A label ending in `:` at the start of the line, such as `seeds:`, is skipped as
+    /// long as it contains no digits. Anything else before the first `:` is kept, so
+    /// no numbers are dropped from unlabeled lines. The rest of the line is split on
+    /// any of the separators, and empty parts are ignored, so repeated separators are
+    /// allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seps` - The characters separating the numbers, such as `&[' ', ',']`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec<T>` with the numbers in order, or an `InputError` if the input is
+    /// empty or a part cannot be parsed.
+    pub fn single_line_numbers<T>(&self, seps: &[char]) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let Some(line) = self.lines.first() else {
+            return Err(InputError::EmptyInput);
+        };
+        let numbers = match line.split_once(':') {
+            Some((label, rest))
+                if !label.trim().is_empty() && !label.chars().any(|c| c.is_ascii_digit()) =>
+            {
+                rest
+            }
+            _ => line.as_str(),
+        };
+        numbers
+            .split(seps)
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                part.parse()
+                    .map_err(|error| InputError::parse(0, line, error))
+            })
+            .collect()
+    }
+
+    /// Collects the positions of every cell equal to a marker character.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker` - The character to look for, such as `'#'`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of the `(row, col)` positions of the marker, in row-major order.
+    /// The positions are signed so that distances between them can be computed directly.
+    pub fn points_of(&self, marker: char) -> Vec<(i64, i64)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(move |&(_, c)| c == marker)
+                    .map(move |(col, _)| (row as i64, col as i64))
+            })
+            .collect()
+    }
+
+    /// Counts the non-overlapping occurrences of a pattern over all lines.
+    ///
+    /// Each line is scanned from left to right, and the scan continues after the
+    /// end of every match. Matches never span line boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `pat` - The string slice to look for.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of occurrences, which is 0 for an empty pattern.
+    pub fn count_matches(&self, pat: &str) -> usize {
+        if pat.is_empty() {
+            return 0;
+        }
+        self.lines
+            .iter()
+            .map(|line| line.matches(pat).count())
+            .sum()
+    }
+
+    /// Counts the occurrences of a pattern over all lines, including overlapping ones.
+    ///
+    /// Every position of every line where the pattern starts counts once, so `"xx"`
+    /// occurs three times in `"xxxx"`. Matches never span line boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `pat` - The string slice to look for.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of occurrences, which is 0 for an empty pattern.
+    pub fn count_matches_overlapping(&self, pat: &str) -> usize {
+        if pat.is_empty() {
+            return 0;
+        }
+        self.lines
+            .iter()
+            .map(|line| {
+                line.char_indices()
+                    .filter(|&(index, _)| line[index..].starts_with(pat))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Finds the index of the longest line, measured in characters.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the index of the first longest line, or `None` if the
+    /// input is empty.
+    pub fn widest_line_index(&self) -> Option<usize> {
+        self.lines
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, line)| line.chars().count())
+            .map(|(index, _)| index)
+    }
+
+    /// Extracts the unsigned integers of each line, ignoring all other characters.
+    ///
+    /// A `-` before a number is ignored like any other character, so `-5` yields `5`.
+    ///
+    /// # Returns
+    ///
+    /// Returns one `Vec<u64>` per line with its integers in the order they appear.
+    pub fn numbers(&self) -> Vec<Vec<u64>> {
+        self.lines.iter().map(|line| extract_uints(line)).collect()
+    }
+
+    /// Extracts the signed integers of each line, ignoring all other characters.
+    ///
+    /// A `-` directly before a number is treated as its sign.
+    ///
+    /// # Returns
+    ///
+    /// Returns one `Vec<i64>` per line with its integers in the order they appear.
+    pub fn signed_numbers(&self) -> Vec<Vec<i64>> {
+        self.lines.iter().map(|line| extract_ints(line)).collect()
+    }
+
+    /// Splits the input into named sections, each starting at a header line.
+    ///
+    /// Every line for which `is_header` returns `true` starts a new section, and the
+    /// following lines up to the next header belong to it. Empty lines are discarded.
+    /// Non-empty lines before the first header form a section with an empty header.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_header` - A function deciding whether a line is a header, such as
+    ///   `|line| line.ends_with("map:")`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Vec` of `(header, lines)` pairs in the order the sections appear.
+    pub fn sections(&self, is_header: impl Fn(&str) -> bool) -> Vec<(&str, Vec<&str>)> {
+        let mut sections: Vec<(&str, Vec<&str>)> = Vec::new();
+        for line in self.lines.iter().map(String::as_str) {
+            if is_header(line) {
+                sections.push((line, Vec::new()));
+            } else if !line.is_empty() {
+                match sections.last_mut() {
+                    Some((_, lines)) => lines.push(line),
+                    None => sections.push(("", vec![line])),
+                }
+            }
+        }
+        sections
+    }
+}
+
+impl<'a> IntoIterator for &'a Input {
+    type Item = &'a str;
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, String>, fn(&String) -> &str>;
+
+    /// Iterates over the lines of the input as string slices, like `Input::iter`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines.iter().map(String::as_str)
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    /// Creates an `Input` from the full text of a puzzle, such as one included with
+    /// `include_str!`.
+    ///
+    /// The text is split into lines as in `load`, and is also kept unchanged so that
+    /// it can be retrieved with `raw`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let lines = Self::normalized_lines(text.as_bytes(), true)
+            .collect::<io::Result<Vec<String>>>()
+            .expect("reading lines from a string slice cannot fail");
+        Ok(Self {
+            lines,
+            raw: Some(text.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    /// Formats the input as its lines joined by `\n`, without a trailing line ending.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.lines.join("\n"))
+    }
+}
+
+/// Writes data to a specified file.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path to the file.
+/// * `data` - A string slice that holds the data to be written.
+///
+/// # Returns
+///
+/// The functions returns an `io::Result<()>`. On success, it returns `Ok(())`,
+/// and on failure, it returns an `io::Error`.
+pub fn write_file(file_path: &str, data: &str) -> io::Result<()> {
+    write_file_p(file_path, data)
+}
+
+/// Writes data to a specified file, creating any missing parent directories.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file, such as a `&str` or a `PathBuf`.
+/// * `data` - A string slice that holds the data to be written.
+///
+/// # Returns
+///
+/// The functions returns an `io::Result<()>`. On success, it returns `Ok(())`,
+/// and on failure, it returns an `io::Error`.
+pub fn write_file_p(path: impl AsRef<Path>, data: &str) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(path)?;
+    file.write_all(data.as_bytes())
+}
+
+/// Reads and returns the contents of a specified file.
+///
+/// # Arguments
+///
+/// * `file_path` - A string slice that holds the path to the file.
+///
+/// # Returns
+///
+/// The functions returns an `io::Result<(String)>`. On success, it returns `Ok(String)`
+/// containing the file contents, and on failure, it returns an `io::Error`.
+pub fn read_file(file_path: &str) -> io::Result<String> {
+    let mut file = File::open(file_path)?;
+    let mut content: String = String::new();
+    file.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// Resolves the path of the puzzle input of a day.
+///
+/// When the `AOC_INPUT_DIR` environment variable is set, the input is looked up as
+/// `{AOC_INPUT_DIR}/{day}/input`, which lets all days read their inputs from another
+/// directory. Otherwise the conventional `./{day}/input` is used.
+///
+/// # Arguments
+///
+/// * `day` - A string slice naming the day's directory, such as `"day01"`.
+///
+/// # Returns
+///
+/// Returns the `PathBuf` of the input file.
+pub fn input_path(day: &str) -> PathBuf {
+    input_path_in(env::var_os("AOC_INPUT_DIR").as_deref().map(Path::new), day)
+}
+
+/// Resolves the path of the puzzle input of a day within an optional input directory.
+fn input_path_in(input_dir: Option<&Path>, day: &str) -> PathBuf {
+    input_dir.unwrap_or(Path::new(".")).join(day).join("input")
+}
+
+/// Lists the places the input of a day is looked up in by `Input::for_day`, in order.
+fn input_candidates(input_dir: Option<&Path>, base: &Path, day: &str) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if input_dir.is_some() {
+        candidates.push(input_path_in(input_dir, day));
+    }
+    candidates.push(input_path_in(Some(base), day));
+    candidates.push(base.join("input"));
+    candidates
+}
+
+/// Finds the first of the candidate paths that is an existing file, skipping directories.
+fn find_existing(candidates: &[PathBuf]) -> io::Result<&PathBuf> {
+    candidates
+        .iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            let searched: Vec<String> = candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no input found, looked in {}", searched.join(", ")),
+            )
+        })
+}
+
+/// Downloads the puzzle input of a day with `curl`, as `start-day.sh` does.
+fn fetch_input(day: u32, session: &str) -> io::Result<String> {
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail"])
+        .args(["--user-agent", "github.com/nizos/aoc23"])
+        .args(["--header", &format!("Cookie: session={session}")])
+        .arg(format!("https://adventofcode.com/2023/day/{day}/input"))
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "failed to download the input of day {day}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let body = String::from_utf8(output.stdout)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if body.trim_start().starts_with('<') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("the server returned a page instead of the input of day {day}"),
+        ));
+    }
+    Ok(body)
+}
+
+/// Runs a function and reports how long it took on stderr.
+///
+/// The report has the form `[time] <label>: <duration>`, which keeps it out of the
+/// answers printed on stdout and easy to find in logs.
+///
+/// # Arguments
+///
+/// * `label` - A string slice naming what is being timed, such as `"part1"`.
+/// * `f` - The function to run.
+///
+/// # Returns
+///
+/// Returns the value returned by `f`, unchanged.
+pub fn timed<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    eprintln!("[time] {label}: {:?}", start.elapsed());
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Result;
+    use tempfile::{tempdir, TempDir};
+
+    // Helper function that creates a temp directory and file
+    fn setup_temp_file(file_name: &str) -> io::Result<(TempDir, String)> {
+        let temp_dir: TempDir = tempdir()?;
+        let file_path_buf = temp_dir.path().join(file_name);
+        let file_path: String = file_path_buf.to_str().unwrap().to_string();
+        Ok((temp_dir, file_path))
+    }
+
+    // Helper function that creates a temp directory and file with content
+    fn setup_temp_file_with_content(
+        file_name: &str,
+        content: &str,
+    ) -> io::Result<(TempDir, String)> {
+        let (temp_dir, file_path) = setup_temp_file(file_name)?;
+        let mut file = File::create(&file_path)?;
+        file.write_all(content.as_bytes())?;
+        Ok((temp_dir, file_path))
+    }
+
+    mod test_input {
+        use crate::test::setup_temp_file_with_content;
+        use crate::{Input, InputError, LineStats};
+
+        #[test]
+        pub fn test_load() -> anyhow::Result<()> {
+            // Given a path to a file that contains multiple lines
+            let file_contents = "Line 1\nLine 2\nLine 3";
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+
+            // When Input is loaded from this file
+            let input = Input::load(&file_path)?;
+
+            // Then it should contain each line of the file in its lines vector
+            assert_eq!(
+                input.lines,
+                vec!["Line 1", "Line 2", "Line 3"],
+                "Input should contain [\"Line 1\", \"Line 2\", \"Line 3\"] \
+                   in its lines vector"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_load_lossy() -> anyhow::Result<()> {
+            // Given a file containing an invalid UTF-8 byte
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", "")?;
+            std::fs::write(&file_path, b"ab\xFFcd\nef\n")?;
+
+            // When Input is loaded lossily from this file
+            let input = Input::load_lossy(&file_path)?;
+
+            // Then the invalid byte should be replaced and the rest of the line kept
+            assert_eq!(
+                input.lines,
+                vec!["ab\u{FFFD}cd", "ef"],
+                "load_lossy should return [\"ab\u{FFFD}cd\", \"ef\"] for the provided file"
+            );
+            assert!(
+                Input::load(&file_path).is_err(),
+                "load should fail for a file with invalid UTF-8"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_from_lines() -> anyhow::Result<()> {
+            // Given an array of lines
+            let lines = ["Line 1", "Line 2", "Line 3"];
+
+            // When Input is created from these lines
+            let input = Input::from_lines(&lines);
+
+            // Then it should contain the lines
+            assert_eq!(
+                input.lines(),
+                &vec!["Line 1", "Line 2", "Line 3"],
+                "Input.lines() should return [\"Line 1\", \"Line 2\", \"Line 3\"]"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_reverse_in_place() {
+            // Given an input of several lines
+            let mut input = Input::from_lines(&["Line 1", "Line 2", "Line 3"]);
+            let expected: Vec<String> = input.lines().iter().rev().cloned().collect();
+
+            // When reverse_in_place is called
+            input.reverse_in_place();
+
+            // Then the lines should be stored in reverse order
+            assert_eq!(
+                input.lines(),
+                &expected,
+                "Input.lines() should return [\"Line 3\", \"Line 2\", \"Line 1\"] \
+                   after reverse_in_place"
+            );
+        }
+
+        #[test]
+        pub fn test_ints() {
+            // Given an input whose integers are spread over several lines
+            let input = Input::from_lines(&["x=1, y=-2", "z=30"]);
+
+            // When ints is called
+            let actual = input.ints();
+
+            // Then it should return every integer across all lines
+            assert_eq!(
+                actual,
+                vec![1, -2, 30],
+                "Input.ints() should return [1, -2, 30] for the provided input"
+            );
+        }
+
+        mod test_grid_view {
+            use crate::Input;
+
+            #[test]
+            pub fn rectangular_input() -> anyhow::Result<()> {
+                // Given a rectangular ASCII input
+                let input = Input::from_lines(&["#.#", "..#"]);
+
+                // When grid_view is called
+                let actual = input.grid_view()?;
+
+                // Then it should return the rows as borrowed bytes
+                assert_eq!(
+                    actual,
+                    vec![b"#.#".as_slice(), b"..#".as_slice()],
+                    "grid_view should return the bytes of each row"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn ragged_input() {
+                // Given an input whose lines differ in length
+                let input = Input::from_lines(&["#.#", "."]);
+
+                // When grid_view is called
+                let actual = input.grid_view();
+
+                // Then it should return an error
+                assert!(
+                    actual.is_err(),
+                    "grid_view should return an error for a ragged input"
+                );
+            }
+        }
+
+        mod test_is_rectangular {
+            use crate::Input;
+
+            #[test]
+            pub fn rectangular_input() {
+                // Then it should return true when all lines share the same length
+                assert!(
+                    Input::from_lines(&["abc", "def"]).is_rectangular(),
+                    "is_rectangular should return true for [\"abc\", \"def\"]"
+                );
+            }
+
+            #[test]
+            pub fn ragged_input() {
+                // Then it should return false when the lines differ in length
+                assert!(
+                    !Input::from_lines(&["abc", "de"]).is_rectangular(),
+                    "is_rectangular should return false for [\"abc\", \"de\"]"
+                );
+            }
+        }
+
+        #[test]
+        pub fn test_diagonals() {
+            // Given a 3x3 grid
+            let input = Input::from_lines(&["abc", "def", "ghi"]);
+
+            // When diagonals is called
+            let actual = input.diagonals();
+
+            // Then it should return the diagonals from the bottom-left to the top-right
+            assert_eq!(
+                actual,
+                vec!["g", "dh", "aei", "bf", "c"],
+                "diagonals should return [\"g\", \"dh\", \"aei\", \"bf\", \"c\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_anti_diagonals() {
+            // Given a 3x3 grid
+            let input = Input::from_lines(&["abc", "def", "ghi"]);
+
+            // When anti_diagonals is called
+            let actual = input.anti_diagonals();
+
+            // Then it should return the anti-diagonals from the top-left to the bottom-right
+            assert_eq!(
+                actual,
+                vec!["a", "bd", "ceg", "fh", "i"],
+                "anti_diagonals should return [\"a\", \"bd\", \"ceg\", \"fh\", \"i\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_enumerate_matching() {
+            // Given an input mixing numeric and non-numeric lines
+            let input = Input::from_lines(&["12", "abc", "34", "d5"]);
+
+            // When enumerate_matching is called with a numeric predicate
+            let actual = input.enumerate_matching(|line| line.chars().all(|c| c.is_ascii_digit()));
+
+            // Then it should return the numeric lines with their indexes
+            assert_eq!(
+                actual,
+                vec![(0, &"12".to_string()), (2, &"34".to_string())],
+                "enumerate_matching should return [(0, \"12\"), (2, \"34\")]"
+            );
+        }
+
+        #[test]
+        pub fn test_max_line_by() {
+            // Given an input with two lines of the greatest length
+            let input = Input::from_lines(&["ab", "abcd", "a", "wxyz"]);
+
+            // When max_line_by is called with the line length as key
+            let actual = input.max_line_by(|line| line.len());
+
+            // Then it should return the first of the longest lines
+            assert_eq!(
+                actual,
+                Some(&"abcd".to_string()),
+                "max_line_by should return \"abcd\" as the longest line"
+            );
+        }
+
+        #[test]
+        pub fn test_min_line_by() {
+            // Given an input with two lines of the smallest length
+            let input = Input::from_lines(&["ab", "c", "abcd", "d"]);
+
+            // When min_line_by is called with the line length as key
+            let actual = input.min_line_by(|line| line.len());
+
+            // Then it should return the first of the shortest lines
+            assert_eq!(
+                actual,
+                Some(&"c".to_string()),
+                "min_line_by should return \"c\" as the shortest line"
+            );
+        }
+
+        mod test_columns_iter {
+            use crate::Input;
+
+            #[test]
+            pub fn rectangular_input() {
+                // Then it should yield each column from left to right
+                assert_eq!(
+                    Input::from_lines(&["abc", "def"])
+                        .columns_iter()
+                        .collect::<Vec<_>>(),
+                    vec!["ad", "be", "cf"],
+                    "columns_iter should yield [\"ad\", \"be\", \"cf\"] for [\"abc\", \"def\"]"
+                );
+            }
+
+            #[test]
+            pub fn ragged_input() {
+                // Then it should pad the columns of shorter lines with spaces
+                assert_eq!(
+                    Input::from_lines(&["abc", "d"])
+                        .columns_iter()
+                        .collect::<Vec<_>>(),
+                    vec!["ad", "b ", "c "],
+                    "columns_iter should yield [\"ad\", \"b \", \"c \"] for [\"abc\", \"d\"]"
+                );
+            }
+        }
+
+        #[test]
+        pub fn test_load_concat() -> anyhow::Result<()> {
+            // Given two files that each contain lines
+            let (first_dir, first_path) = setup_temp_file_with_content("first.txt", "a\nb")?;
+            let (second_dir, second_path) = setup_temp_file_with_content("second.txt", "c")?;
+
+            // When Input is loaded from both files
+            let input = Input::load_concat(&[&first_path, &second_path])?;
+
+            // Then it should contain the lines of both files in order
+            assert_eq!(
+                input.lines(),
+                &vec!["a", "b", "c"],
+                "Input.lines() should return [\"a\", \"b\", \"c\"]"
+            );
+            drop(first_dir);
+            drop(second_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_load_concat_missing_file() -> anyhow::Result<()> {
+            // Given an existing file and a missing one
+            let (temp_dir, file_path) = setup_temp_file_with_content("first.txt", "a")?;
+            let missing = temp_dir.path().join("missing.txt");
+
+            // When Input is loaded from both files
+            let actual = Input::load_concat(&[&file_path, missing.to_str().unwrap()]);
+
+            // Then it should return an error
+            assert!(
+                actual.is_err(),
+                "load_concat should return an error when a file is missing"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_from_grid() {
+            // Given the characters of an input as a grid, including an empty row
+            let input = Input::from_lines(&["#.#", "", "..#"]);
+            let grid: Vec<Vec<char>> = input
+                .lines()
+                .iter()
+                .map(|line| line.chars().collect())
+                .collect();
+
+            // When Input is created from the grid
+            let actual = Input::from_grid(&grid);
+
+            // Then it should contain the original lines
+            assert_eq!(
+                actual.lines(),
+                input.lines(),
+                "Input.lines() should return [\"#.#\", \"\", \"..#\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_tokenize() {
+            // Given a line containing several kinds of delimiters
+            let input = Input::from_lines(&["Game 1: 3, 4"]);
+
+            // When tokenize is called with those delimiters
+            let actual = input.tokenize(&[',', ' ', ':']);
+
+            // Then it should return the non-empty tokens of the line
+            assert_eq!(
+                actual,
+                vec![vec!["Game", "1", "3", "4"]],
+                "tokenize should return [[\"Game\", \"1\", \"3\", \"4\"]] for \"Game 1: 3, 4\""
+            );
+        }
+
+        mod test_column_sums {
+            use crate::Input;
+
+            #[test]
+            pub fn numeric_table() -> anyhow::Result<()> {
+                // Given a table of two rows and three columns
+                let input = Input::from_lines(&["1 2 3", "10 -20 30"]);
+
+                // When column_sums is called
+                let actual = input.column_sums()?;
+
+                // Then it should return the sum of each column
+                assert_eq!(
+                    actual,
+                    vec![11, -18, 33],
+                    "column_sums should return [11, -18, 33] for the provided table"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn unequal_columns() {
+                // Then it should return an error when the rows differ in column count
+                assert!(
+                    Input::from_lines(&["1 2 3", "4 5"]).column_sums().is_err(),
+                    "column_sums should return an error for rows of 3 and 2 columns"
+                );
+            }
+        }
+
+        mod test_split_at {
+            use crate::Input;
+
+            #[test]
+            pub fn index_within_input() {
+                // Given an input of five lines
+                let input = Input::from_lines(&["a", "b", "c", "d", "e"]);
+
+                // When split_at is called with an index of 2
+                let (head, tail) = input.split_at(2);
+
+                // Then it should split the input before the third line
+                assert_eq!(
+                    head.lines(),
+                    &vec!["a", "b"],
+                    "split_at should return [\"a\", \"b\"] as the first part"
+                );
+                assert_eq!(
+                    tail.lines(),
+                    &vec!["c", "d", "e"],
+                    "split_at should return [\"c\", \"d\", \"e\"] as the second part"
+                );
+            }
+
+            #[test]
+            pub fn index_out_of_range() {
+                // Given an input of two lines
+                let input = Input::from_lines(&["a", "b"]);
+
+                // When split_at is called with an index past the end
+                let (head, tail) = input.split_at(10);
+
+                // Then it should put every line in the first part
+                assert_eq!(
+                    (head.lines().len(), tail.lines().len()),
+                    (2, 0),
+                    "split_at should clamp an index past the end"
+                );
+            }
+        }
+
+        mod test_row_bands {
+            use crate::Input;
+
+            #[test]
+            pub fn overlapping_bands() {
+                // Given an input of three rows
+                let input = Input::from_lines(&["a", "b", "c"]);
+
+                // When row_bands is called with n = 2
+                let actual: Vec<Vec<&String>> = input.row_bands(2).collect();
+
+                // Then it should yield both overlapping bands of two rows
+                assert_eq!(
+                    actual,
+                    vec![vec!["a", "b"], vec!["b", "c"]],
+                    "row_bands should yield [[\"a\", \"b\"], [\"b\", \"c\"]] for n = 2"
+                );
+            }
+
+            #[test]
+            pub fn band_larger_than_input() {
+                // Then it should yield nothing when n exceeds the number of rows
+                assert_eq!(
+                    Input::from_lines(&["a", "b", "c"]).row_bands(4).count(),
+                    0,
+                    "row_bands should yield nothing for n = 4 over three rows"
+                );
+            }
+        }
+
+        #[test]
+        pub fn test_block_count() {
+            // Given an input of three blocks surrounded by and separated by empty lines
+            let input = Input::from_lines(&["", "a", "b", "", "c", "", "", "d", ""]);
+
+            // When block_count is called
+            let actual = input.block_count();
+
+            // Then it should return the number of blocks
+            assert_eq!(
+                actual, 3,
+                "block_count should return 3 for the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_char_positions() {
+            // Given a grid with two kinds of markers
+            let input = Input::from_lines(&["S.E", ".S."]);
+
+            // When char_positions is called
+            let actual = input.char_positions();
+
+            // Then it should return the positions of each marker
+            assert_eq!(
+                actual.get(&'S'),
+                Some(&vec![(0, 0), (1, 1)]),
+                "char_positions should return [(0, 0), (1, 1)] for 'S'"
+            );
+            assert_eq!(
+                actual.get(&'E'),
+                Some(&vec![(0, 2)]),
+                "char_positions should return [(0, 2)] for 'E'"
+            );
+        }
+
+        #[test]
+        pub fn test_load_strips_bom() -> anyhow::Result<()> {
+            // Given a file that starts with a UTF-8 byte order mark
+            let (temp_dir, file_path) =
+                setup_temp_file_with_content("test.txt", "\u{FEFF}Line 1\nLine 2")?;
+
+            // When Input is loaded from this file
+            let input = Input::load(&file_path)?;
+
+            // Then the first line should start with its first real character
+            assert_eq!(
+                input.lines(),
+                &vec!["Line 1", "Line 2"],
+                "Input should contain [\"Line 1\", \"Line 2\"] without the byte order mark"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        mod test_int_pairs {
+            use crate::Input;
+
+            #[test]
+            pub fn two_columns() -> anyhow::Result<()> {
+                // Given a two-column numeric input with a trailing empty line
+                let input = Input::from_lines(&["3   4", "-4  3", ""]);
+
+                // When int_pairs is called
+                let actual = input.int_pairs()?;
+
+                // Then it should return one pair per non-empty line
+                assert_eq!(
+                    actual,
+                    vec![(3, 4), (-4, 3)],
+                    "int_pairs should return [(3, 4), (-4, 3)] for the provided input"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn malformed_line() {
+                // Then it should return an error for a line with three values
+                assert!(
+                    Input::from_lines(&["3 4", "1 2 3"]).int_pairs().is_err(),
+                    "int_pairs should return an error for the line \"1 2 3\""
+                );
+            }
+        }
+
+        mod test_interleave {
+            use crate::Input;
+
+            #[test]
+            pub fn equal_lengths() {
+                // Given two inputs of the same length
+                let first = Input::from_lines(&["a", "b"]);
+                let second = Input::from_lines(&["1", "2"]);
+
+                // Then it should alternate between their lines
+                assert_eq!(
+                    first.interleave(&second).lines(),
+                    &vec!["a", "1", "b", "2"],
+                    "interleave should return [\"a\", \"1\", \"b\", \"2\"]"
+                );
+            }
+
+            #[test]
+            pub fn different_lengths() {
+                // Given a short input and a longer one
+                let first = Input::from_lines(&["a"]);
+                let second = Input::from_lines(&["1", "2", "3"]);
+
+                // Then it should append the leftover lines of the longer input
+                assert_eq!(
+                    first.interleave(&second).lines(),
+                    &vec!["a", "1", "2", "3"],
+                    "interleave should return [\"a\", \"1\", \"2\", \"3\"]"
+                );
+            }
+        }
+
+        #[test]
+        pub fn test_iter() {
+            // Given an input of several lines
+            let input = Input::from_lines(&["Line 1", "Line 2", "Line 3"]);
+
+            // When iter is called
+            let actual: Vec<&str> = input.iter().collect();
+
+            // Then it should yield each line as a string slice
+            assert_eq!(
+                actual,
+                vec!["Line 1", "Line 2", "Line 3"],
+                "Input.iter() should yield [\"Line 1\", \"Line 2\", \"Line 3\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_into_iterator() {
+            // Given an input of several lines
+            let input = Input::from_lines(&["Line 1", "Line 2"]);
+
+            // When the input is iterated by reference
+            let mut actual = Vec::new();
+            for line in &input {
+                actual.push(line);
+            }
+
+            // Then it should yield each line as a string slice
+            assert_eq!(
+                actual,
+                vec!["Line 1", "Line 2"],
+                "iterating over &Input should yield [\"Line 1\", \"Line 2\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_len() {
+            // Then it should return the number of lines
+            assert_eq!(
+                Input::from_lines(&["a", "b", "c"]).len(),
+                3,
+                "Input.len() should return 3 for an input of three lines"
+            );
+        }
+
+        mod test_is_empty {
+            use crate::test::setup_temp_file_with_content;
+            use crate::Input;
+
+            #[test]
+            pub fn from_no_lines() {
+                // Then it should return true for an input created from no lines
+                assert!(
+                    Input::from_lines(&[]).is_empty(),
+                    "Input.is_empty() should return true for from_lines(&[])"
+                );
+            }
+
+            #[test]
+            pub fn from_empty_file() -> anyhow::Result<()> {
+                // Given an empty file
+                let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", "")?;
+
+                // When Input is loaded from this file
+                let input = Input::load(&file_path)?;
+
+                // Then it should be empty
+                assert!(
+                    input.is_empty(),
+                    "Input.is_empty() should return true for an empty file"
+                );
+                drop(temp_dir);
+                Ok(())
+            }
+
+            #[test]
+            pub fn with_lines() {
+                // Then it should return false for an input containing lines
+                assert!(
+                    !Input::from_lines(&["a"]).is_empty(),
+                    "Input.is_empty() should return false for an input of one line"
+                );
+            }
+        }
+
+        mod test_from_reader {
+            use crate::Input;
+            use std::io::Cursor;
+
+            #[test]
+            pub fn lf_line_endings() -> anyhow::Result<()> {
+                // Given a buffer with LF line endings and a final line ending
+                let reader = Cursor::new(b"Line 1\nLine 2\n".to_vec());
+
+                // When Input is read from the buffer
+                let input = Input::from_reader(reader)?;
+
+                // Then it should contain each line without a trailing empty line
+                assert_eq!(
+                    input.lines(),
+                    &vec!["Line 1", "Line 2"],
+                    "Input should contain [\"Line 1\", \"Line 2\"] for LF content"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn crlf_line_endings() -> anyhow::Result<()> {
+                // Given a buffer with CRLF line endings, the last one without a line feed
+                let reader = Cursor::new(b"Line 1\r\nLine 2\r\nLine 3\r".to_vec());
+
+                // When Input is read from the buffer
+                let input = Input::from_reader(reader)?;
+
+                // Then it should contain each line without carriage returns
+                assert_eq!(
+                    input.lines(),
+                    &vec!["Line 1", "Line 2", "Line 3"],
+                    "Input should contain [\"Line 1\", \"Line 2\", \"Line 3\"] for CRLF content"
+                );
+                Ok(())
+            }
+        }
+
+        #[test]
+        pub fn test_replace_lines_where() {
+            // Given an input where only some lines start with "Card"
+            let input = Input::from_lines(&["Card 1: 41 48", "Time: 7", "Card 2: 13 32"]);
+
+            // When replace_lines_where is called for the lines starting with "Card"
+            let actual = input.replace_lines_where(
+                |line| line.starts_with("Card"),
+                |line| line.replace("Card", "Ticket"),
+            );
+
+            // Then only those lines should be replaced
+            assert_eq!(
+                actual.lines(),
+                &vec!["Ticket 1: 41 48", "Time: 7", "Ticket 2: 13 32"],
+                "replace_lines_where should only replace the lines starting with \"Card\""
+            );
+        }
+
+        #[test]
+        pub fn test_load_with_progress() -> anyhow::Result<()> {
+            // Given a path to a file that contains multiple lines
+            let file_contents = "Line 1\r\nLine 2\nLine 3\n";
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+
+            // When Input is loaded from this file with a progress callback
+            let mut reported = Vec::new();
+            let input =
+                Input::load_with_progress(&file_path, |line_number| reported.push(line_number))?;
+
+            // Then the callback should be invoked once per line with increasing indices
+            assert_eq!(
+                reported,
+                vec![0, 1, 2],
+                "load_with_progress should report the lines 0, 1 and 2"
+            );
+            // And the input should equal the input loaded without progress
+            assert_eq!(
+                input.lines,
+                Input::load(&file_path)?.lines,
+                "load_with_progress should return the same lines as load"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_blocks_two_blocks() {
+            // Given an input with two blocks separated by an empty line
+            let input = Input::from_lines(&["a", "b", "", "c"]);
+
+            // Then blocks should return both blocks without the separator
+            assert_eq!(
+                input.blocks(),
+                vec![vec!["a", "b"], vec!["c"]],
+                "blocks should return [[\"a\", \"b\"], [\"c\"]] for the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_blocks_single_block() {
+            // Given an input without empty lines
+            let input = Input::from_lines(&["a", "b", "c"]);
+
+            // Then blocks should return a single block with all lines
+            assert_eq!(
+                input.blocks(),
+                vec![vec!["a", "b", "c"]],
+                "blocks should return a single block for an input without empty lines"
+            );
+        }
+
+        #[test]
+        pub fn test_blocks_consecutive_empty_lines() {
+            // Given an input with leading, trailing and consecutive empty lines
+            let input = Input::from_lines(&["", "a", "", "", "b", "c", ""]);
+
+            // Then blocks should not return any empty blocks
+            assert_eq!(
+                input.blocks(),
+                vec![vec!["a"], vec!["b", "c"]],
+                "blocks should return [[\"a\"], [\"b\", \"c\"]] for the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_parse_blocks() -> anyhow::Result<()> {
+            // Given an input with blocks of calorie counts
+            let input = Input::from_lines(&["1000", "2000", "", "4000", "", "5000", "6000"]);
+
+            // When parse_blocks is called with a parser summing each block
+            let actual = input.parse_blocks(|block| {
+                block
+                    .iter()
+                    .map(|line| Ok(line.parse::<u32>()?))
+                    .sum::<anyhow::Result<u32>>()
+            })?;
+
+            // Then it should return the total of every block
+            assert_eq!(
+                actual,
+                vec![3000, 4000, 11000],
+                "parse_blocks should return [3000, 4000, 11000] for the provided input"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_parse_blocks_error() {
+            // Given an input with a block that cannot be parsed
+            let input = Input::from_lines(&["1000", "", "abc"]);
+
+            // When parse_blocks is called with a parser summing each block
+            let actual = input.parse_blocks(|block| {
+                block
+                    .iter()
+                    .map(|line| Ok(line.parse::<u32>()?))
+                    .sum::<anyhow::Result<u32>>()
+            });
+
+            // Then it should return an error
+            assert!(
+                actual.is_err(),
+                "parse_blocks should return an error for a block containing \"abc\""
+            );
+        }
+
+        #[test]
+        pub fn test_line_stats() {
+            // Given an input with lines of different lengths
+            let input = Input::from_lines(&["abc", "", "abcdefgh", "a"]);
+
+            // When line_stats is called
+            let actual = input.line_stats();
+
+            // Then it should return the count, total, longest and average lengths
+            assert_eq!(
+                actual,
+                LineStats {
+                    count: 4,
+                    total_chars: 12,
+                    max_len: 8,
+                    average_len: 3.0,
+                },
+                "line_stats should return 4 lines with 12 characters, at most 8 per line"
+            );
+            assert_eq!(
+                input.total_chars(),
+                12,
+                "total_chars should return 12 for the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_line_stats_empty() {
+            // Then it should return zeroes for an empty input
+            assert_eq!(
+                Input::from_lines(&[]).line_stats(),
+                LineStats {
+                    count: 0,
+                    total_chars: 0,
+                    max_len: 0,
+                    average_len: 0.0,
+                },
+                "line_stats should return zeroes for an empty input"
+            );
+        }
+
+        #[test]
+        pub fn test_rows_matching() {
+            // Given an input of rows with different centers and lengths
+            let input = Input::from_lines(&[".#.", "###", "..#", "#.#", ".#", "##.."]);
+
+            // When rows_matching is called with a wildcard pattern
+            let actual = input.rows_matching("?#?");
+
+            // Then it should return the rows of the same length with a '#' in the center
+            assert_eq!(
+                actual,
+                vec![0, 1],
+                "rows_matching should return [0, 1] for the pattern \"?#?\""
+            );
+        }
+
+        #[test]
+        pub fn test_parse_lines() -> anyhow::Result<()> {
+            // Given an input of numeric lines
+            let input = Input::from_lines(&["3", "7", "12"]);
+
+            // When parse_lines is called
+            let actual: Vec<i32> = input.parse_lines()?;
+
+            // Then it should return the parsed value of every line
+            assert_eq!(
+                actual,
+                vec![3, 7, 12],
+                "parse_lines should return [3, 7, 12] for the provided input"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_parse_lines_error() {
+            // Given an input containing a non-numeric line
+            let input = Input::from_lines(&["3", "seven", "12"]);
+
+            // When parse_lines is called
+            let actual = input.parse_lines::<i32>();
+
+            // Then it should return an error naming the number and content of that line
+            assert_eq!(
+                actual.map_err(|error| error.to_string()),
+                Err("failed to parse line 2: \"seven\"".to_string()),
+                "parse_lines should return an error naming line 2 and \"seven\""
+            );
+        }
+
+        #[test]
+        pub fn test_split_lines_on() -> anyhow::Result<()> {
+            // Given lines of semicolon-separated numbers
+            let input = Input::from_lines(&["1; 2", "3;4;5"]);
+
+            // When split_lines_on is called
+            let actual: Vec<Vec<u32>> = input.split_lines_on(";")?;
+
+            // Then it should return the parsed pieces of every line
+            assert_eq!(
+                actual,
+                vec![vec![1, 2], vec![3, 4, 5]],
+                "split_lines_on should return [[1, 2], [3, 4, 5]] for the provided input"
+            );
+            // And it should name the line of a piece that can not be parsed
+            assert_eq!(
+                Input::from_lines(&["1;2", "3;x"])
+                    .split_lines_on::<u32>(";")
+                    .map_err(|error| error.to_string()),
+                Err("failed to parse line 2: \"3;x\"".to_string()),
+                "split_lines_on should return an error naming line 2 and \"3;x\""
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_split_commas() {
+            // Given an initialization sequence wrapped over two lines
+            let input = Input::from_lines(&["rn=1,cm-,qp", "=3,pc-,"]);
+
+            // When split_commas is called
+            let actual = input.split_commas();
+
+            // Then it should return the steps of the whole sequence, ignoring line breaks
+            assert_eq!(
+                actual,
+                vec!["rn=1", "cm-", "qp=3", "pc-"],
+                "split_commas should return [\"rn=1\", \"cm-\", \"qp=3\", \"pc-\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_columns() {
+            // Given a ragged input
+            let input = Input::from_lines(&["#.#", "..", "##."]);
+
+            // When columns is called
+            let actual = input.columns();
+
+            // Then it should return every column, padding the short line with a space
+            assert_eq!(
+                actual,
+                vec!["#.#", "..#", "# ."],
+                "columns should return [\"#.#\", \"..#\", \"# .\"] for the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_columns_iter_matches_columns() {
+            // Given a rectangular and a ragged input
+            let inputs = [
+                Input::from_lines(&["#.#", "..#", "##."]),
+                Input::from_lines(&["abc", "", "de"]),
+            ];
+
+            // Then columns_iter should yield exactly the columns returned by columns
+            for input in inputs {
+                assert_eq!(
+                    input.columns_iter().collect::<Vec<_>>(),
+                    input.columns(),
+                    "columns_iter should match columns for {:?}",
+                    input.lines()
+                );
+            }
+        }
+
+        #[test]
+        pub fn test_transposed() {
+            // Given a ragged input
+            let input = Input::from_lines(&["abc", "d"]);
+
+            // When transposed is called twice
+            let once = input.transposed();
+            let twice = once.transposed();
+
+            // Then it should turn columns into lines and back, keeping the padding
+            assert_eq!(
+                once.lines(),
+                &["ad", "b ", "c "],
+                "transposed should turn the columns of the provided input into lines"
+            );
+            assert_eq!(
+                twice.lines(),
+                &["abc", "d  "],
+                "transposed twice should restore the padded lines of the provided input"
+            );
+        }
+
+        #[test]
+        pub fn test_zip_cells() {
+            // Given two small grids that differ in one cell
+            let first = Input::from_lines(&["#.", ".#"]);
+            let second = Input::from_lines(&["#.", "##"]);
+
+            // When zip_cells is called and the differing cells are kept
+            let actual: Vec<_> = first
+                .zip_cells(&second)
+                .filter(|(_, a, b)| a != b)
+                .collect();
+
+            // Then it should return the position and both characters of that cell
+            assert_eq!(
+                actual,
+                vec![((1, 0), '.', '#')],
+                "zip_cells should only differ at (1, 0) for the provided grids"
+            );
+            assert_eq!(
+                first.zip_cells(&second).count(),
+                4,
+                "zip_cells should yield every cell of two 2x2 grids"
+            );
+        }
+
+        #[test]
+        pub fn test_display() {
+            // Then it should join the lines with line breaks and no trailing line break
+            assert_eq!(
+                Input::from_lines(&["a", "b"]).to_string(),
+                "a\nb",
+                "Input should display as \"a\\nb\" for the lines [\"a\", \"b\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_save_round_trip() -> anyhow::Result<()> {
+            // Given an input loaded from a file
+            let file_contents = "Line 1\nLine 2\nLine 3";
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+            let input = Input::load(&file_path)?;
+
+            // When it is saved to another file
+            let saved_path = format!("{file_path}.saved");
+            input.save(&saved_path)?;
+
+            // Then the saved file should have the same contents as the original
+            assert_eq!(
+                std::fs::read_to_string(&saved_path)?,
+                file_contents,
+                "save should write the same contents as the loaded file"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_single_line_numbers() -> anyhow::Result<()> {
+            // Given an input with a labeled line of numbers
+            let input = Input::from_lines(&["seeds: 79 14 55 13", "", "seed-to-soil map:"]);
+
+            // When single_line_numbers is called with a space separator
+            let actual: Vec<u64> = input.single_line_numbers(&[' '])?;
+
+            // Then it should return the numbers of the first line without the label
+            assert_eq!(
+                actual,
+                vec![79, 14, 55, 13],
+                "single_line_numbers should return [79, 14, 55, 13] for \"seeds: 79 14 55 13\""
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_single_line_numbers_colon_separator() -> anyhow::Result<()> {
+            // Then it should keep the first number when ':' is one of the separators
+            assert_eq!(
+                Input::from_lines(&["1:2:3"]).single_line_numbers::<u64>(&[':'])?,
+                vec![1, 2, 3],
+                "single_line_numbers should return [1, 2, 3] for \"1:2:3\""
+            );
+            // And it should still skip a label in front of the numbers
+            assert_eq!(
+                Input::from_lines(&["time:7:15"]).single_line_numbers::<u64>(&[':'])?,
+                vec![7, 15],
+                "single_line_numbers should return [7, 15] for \"time:7:15\""
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_single_line_numbers_unlabeled_colon() {
+            // Then it should not drop the numbers before a ':' on an unlabeled line
+            assert_eq!(
+                Input::from_lines(&["1 2:3"])
+                    .single_line_numbers::<u64>(&[' '])
+                    .map_err(|error| error.to_string()),
+                Err("failed to parse line 1: \"1 2:3\"".to_string()),
+                "single_line_numbers should report \"2:3\" instead of returning [3]"
+            );
+        }
+
+        #[test]
+        pub fn test_single_line_numbers_invalid() {
+            // Then it should return an error for a part that is not a number
+            assert!(
+                Input::from_lines(&["1,2,x"])
+                    .single_line_numbers::<u64>(&[','])
+                    .is_err(),
+                "single_line_numbers should return an error for \"1,2,x\""
+            );
+        }
+
+        #[test]
+        pub fn test_windows() {
+            // Given an input of four lines
+            let input = Input::from_lines(&["a", "b", "c", "d"]);
+
+            // Then windows should yield every run of consecutive lines of the given size
+            assert_eq!(
+                input.windows(2).collect::<Vec<_>>(),
+                vec![&["a", "b"], &["b", "c"], &["c", "d"]],
+                "windows(2) should yield [a, b], [b, c] and [c, d]"
+            );
+            assert_eq!(
+                input.windows(3).collect::<Vec<_>>(),
+                vec![&["a", "b", "c"], &["b", "c", "d"]],
+                "windows(3) should yield [a, b, c] and [b, c, d]"
+            );
+        }
+
+        #[test]
+        pub fn test_windows_longer_than_input() {
+            // Then windows should yield nothing when the size exceeds the number of lines
+            assert_eq!(
+                Input::from_lines(&["a", "b"]).windows(3).count(),
+                0,
+                "windows(3) should yield nothing for an input of two lines"
+            );
+        }
+
+        #[test]
+        pub fn test_points_of() {
+            // Given a grid with scattered '#' markers
+            let input = Input::from_lines(&["...#", "#...", "..#."]);
+
+            // When points_of is called for '#'
+            let actual = input.points_of('#');
+
+            // Then it should return the position of every marker in row-major order
+            assert_eq!(
+                actual,
+                vec![(0, 3), (1, 0), (2, 2)],
+                "points_of should return [(0, 3), (1, 0), (2, 2)] for the provided grid"
+            );
+        }
+
+        #[test]
+        pub fn test_count_matches() {
+            // Given an input containing a repeated pattern
+            let input = Input::from_lines(&["xxxx"]);
+
+            // Then overlapping matches should only be counted by the overlapping variant
+            assert_eq!(
+                input.count_matches("xx"),
+                2,
+                "count_matches should return 2 for \"xx\" in \"xxxx\""
+            );
+            assert_eq!(
+                input.count_matches_overlapping("xx"),
+                3,
+                "count_matches_overlapping should return 3 for \"xx\" in \"xxxx\""
+            );
+        }
+
+        #[test]
+        pub fn test_count_matches_absent_or_empty() {
+            // Given an input of several lines
+            let input = Input::from_lines(&["XMAS", "SAMX"]);
+
+            // Then an absent or empty pattern should not be found
+            assert_eq!(
+                (
+                    input.count_matches("XMASX"),
+                    input.count_matches_overlapping("XMASX")
+                ),
+                (0, 0),
+                "count_matches should return 0 for a pattern absent from the input"
+            );
+            assert_eq!(
+                (input.count_matches(""), input.count_matches_overlapping("")),
+                (0, 0),
+                "count_matches should return 0 for an empty pattern"
+            );
+        }
+
+        #[test]
+        pub fn test_widest_line_index() {
+            // Given a ragged input with two longest lines
+            let input = Input::from_lines(&["ab", "abcd", "a", "wxyz"]);
+
+            // Then widest_line_index should return the index of the first longest line
+            assert_eq!(
+                input.widest_line_index(),
+                Some(1),
+                "widest_line_index should return 1 for the provided input"
+            );
+            assert_eq!(
+                Input::from_lines(&[]).widest_line_index(),
+                None,
+                "widest_line_index should return None for an empty input"
+            );
+        }
+
+        #[test]
+        pub fn test_load_trimmed() -> anyhow::Result<()> {
+            // Given a file with trailing and leading whitespace
+            let file_contents = "abc   \n  def\n";
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+
+            // When it is loaded with and without trimming
+            let raw = Input::load(&file_path)?;
+            let trimmed = Input::load_trimmed(&file_path)?;
+
+            // Then only load_trimmed should remove the trailing whitespace
+            assert_eq!(
+                raw.lines,
+                vec!["abc   ", "  def"],
+                "load should keep the whitespace of every line"
+            );
+            assert_eq!(
+                trimmed.lines,
+                vec!["abc", "  def"],
+                "load_trimmed should only remove trailing whitespace"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_as_grid() -> anyhow::Result<()> {
+            // Given a rectangular input
+            let input = Input::from_lines(&["ab", "cd"]);
+
+            // Then as_grid and as_byte_grid should return its rows
+            assert_eq!(
+                input.as_grid()?,
+                vec![vec!['a', 'b'], vec!['c', 'd']],
+                "as_grid should return the characters of every row"
+            );
+            assert_eq!(
+                input.as_byte_grid()?,
+                vec![b"ab".to_vec(), b"cd".to_vec()],
+                "as_byte_grid should return the bytes of every row"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_as_grid_ragged() {
+            // Given an input whose second line is shorter than the first
+            let input = Input::from_lines(&["abc", "de", "fgh"]);
+
+            // Then as_grid should return an error naming that line
+            assert_eq!(
+                input.as_grid().map_err(|error| error.to_string()),
+                Err("line 2 has length 2, expected 3".to_string()),
+                "as_grid should report that line 2 has length 2 instead of 3"
+            );
+            assert!(
+                input.as_byte_grid().is_err(),
+                "as_byte_grid should return an error for a ragged input"
+            );
+        }
+
+        #[test]
+        pub fn test_structured_errors() {
+            // Then each kind of failure should be reported with the line it occurred on
+            assert!(
+                matches!(
+                    Input::from_lines(&["1 2", "3 x"]).int_pairs(),
+                    Err(InputError::Parse { line_no: 2, ref content, .. }) if content == "3 x"
+                ),
+                "int_pairs should report a parse error on line 2 for \"3 x\""
+            );
+            assert!(
+                matches!(
+                    Input::from_lines(&["1 2", "", "3 4 5"]).int_pairs(),
+                    Err(InputError::ValueCount {
+                        line_no: 3,
+                        count: 3,
+                        expected: 2
+                    })
+                ),
+                "int_pairs should report 3 values instead of 2 on line 3"
+            );
+            assert!(
+                matches!(
+                    Input::from_lines(&["#.#", "."]).grid_view(),
+                    Err(InputError::RaggedGrid {
+                        line_no: 2,
+                        len: 1,
+                        expected: 3
+                    })
+                ),
+                "grid_view should report that line 2 has length 1 instead of 3"
+            );
+            assert!(
+                matches!(
+                    Input::from_lines(&["#.#", "#é"]).grid_view(),
+                    Err(InputError::NonAscii { line_no: 2 })
+                ),
+                "grid_view should report non-ASCII characters on line 2"
+            );
+            assert!(
+                matches!(
+                    Input::from_lines(&[]).single_line_numbers::<u64>(&[' ']),
+                    Err(InputError::EmptyInput)
+                ),
+                "single_line_numbers should report an empty input"
+            );
+        }
+
+        #[test]
+        pub fn test_stream() -> anyhow::Result<()> {
+            // Given a file with a byte order mark and mixed line endings
+            let file_contents = "\u{FEFF}Line 1\r\nLine 2\nLine 3\n";
+            let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", file_contents)?;
+
+            // When the file is streamed
+            let actual = Input::stream(&file_path)?.collect::<std::io::Result<Vec<_>>>()?;
+
+            // Then it should yield the same lines as load
+            assert_eq!(
+                actual,
+                Input::load(&file_path)?.lines,
+                "stream should yield the same lines as load"
+            );
+            assert_eq!(
+                actual,
+                vec!["Line 1", "Line 2", "Line 3"],
+                "stream should yield [\"Line 1\", \"Line 2\", \"Line 3\"]"
+            );
+            drop(temp_dir);
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_from_str() {
+            // Given the full text of a puzzle with a trailing line ending
+            let text = "Line 1\r\nLine 2\n";
+
+            // When it is parsed into an Input
+            let input: Input = text.parse().unwrap();
+
+            // Then the lines should be split as in load and the text kept unchanged
+            assert_eq!(
+                input.lines,
+                vec!["Line 1", "Line 2"],
+                "Input should contain [\"Line 1\", \"Line 2\"] in its lines vector"
+            );
+            assert_eq!(
+                input.raw(),
+                text,
+                "raw should return the original text, including the line endings"
+            );
+        }
+
+        #[test]
+        pub fn test_from_str_matches_load() -> anyhow::Result<()> {
+            // Given texts with a byte order mark and unusual line endings
+            for text in ["\u{FEFF}Line 1\nLine 2", "a\rb\r", "a\r\nb\r", "a\n\n"] {
+                let (temp_dir, file_path) = setup_temp_file_with_content("test.txt", text)?;
+
+                // Then parsing should split them exactly as load does
+                assert_eq!(
+                    text.parse::<Input>()?.lines,
+                    Input::load(&file_path)?.lines,
+                    "from_str should split {text:?} like load"
+                );
+                drop(temp_dir);
+            }
+            Ok(())
+        }
+
+        #[test]
+        pub fn test_raw_without_text() {
+            // Then raw should join the lines of an Input built from lines
+            assert_eq!(
+                Input::from_lines(&["a", "b"]).raw(),
+                "a\nb",
+                "raw should return \"a\\nb\" for the lines [\"a\", \"b\"]"
+            );
+        }
+
+        #[test]
+        pub fn test_numbers() {
+            // Given an input of labeled lines of numbers
+            let input = Input::from_lines(&["Card 4: 41 48 | 83", "Time: -7 15", ""]);
+
+            // Then numbers and signed_numbers should return the integers of every line
+            assert_eq!(
+                input.numbers(),
+                vec![vec![4, 41, 48, 83], vec![7, 15], vec![]],
+                "numbers should ignore signs and return the integers of every line"
+            );
+            assert_eq!(
+                input.signed_numbers(),
+                vec![vec![4, 41, 48, 83], vec![-7, 15], vec![]],
+                "signed_numbers should keep the sign of -7"
+            );
+        }
+
+        mod test_load_with {
+            use crate::test::setup_temp_file_with_content;
+            use crate::{EmptyLines, Input, LoadOptions, Trim};
+
+            const FILE_CONTENTS: &str = "  a \r\n\r\nb\r\n  \r\n\r\n";
+
+            fn load_with(options: LoadOptions) -> anyhow::Result<Vec<String>> {
+                let (temp_dir, file_path) =
+                    setup_temp_file_with_content("test.txt", FILE_CONTENTS)?;
+                let input = Input::load_with(&file_path, options)?;
+                drop(temp_dir);
+                Ok(input.lines)
+            }
+
+            #[test]
+            pub fn default_options() -> anyhow::Result<()> {
+                // Then the default options should behave like load
+                let (temp_dir, file_path) =
+                    setup_temp_file_with_content("test.txt", FILE_CONTENTS)?;
+                assert_eq!(
+                    Input::load_with(&file_path, LoadOptions::default())?.lines,
+                    Input::load(&file_path)?.lines,
+                    "load_with should return the same lines as load by default"
+                );
+                drop(temp_dir);
+                Ok(())
+            }
+
+            #[test]
+            pub fn keep_cr() -> anyhow::Result<()> {
+                // Then the carriage returns should be kept when CRLF stripping is disabled
+                assert_eq!(
+                    load_with(LoadOptions::default().strip_cr(false))?,
+                    vec!["  a \r", "\r", "b\r", "  \r", "\r"],
+                    "load_with should keep the \\r of every line"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn trim_and_skip_empty_lines() -> anyhow::Result<()> {
+                // Then trimmed lines that end up empty should be skipped
+                assert_eq!(
+                    load_with(
+                        LoadOptions::default()
+                            .trim(Trim::Both)
+                            .empty_lines(EmptyLines::Skip)
+                    )?,
+                    vec!["a", "b"],
+                    "load_with should return [\"a\", \"b\"] when trimming and skipping empty lines"
+                );
+                Ok(())
+            }
+
+            #[test]
+            pub fn skip_trailing_empty_lines() -> anyhow::Result<()> {
+                // Then only the empty lines at the end should be removed
+                assert_eq!(
+                    load_with(
+                        LoadOptions::default()
+                            .trim(Trim::End)
+                            .empty_lines(EmptyLines::SkipTrailing)
+                    )?,
+                    vec!["  a", "", "b"],
+                    "load_with should only remove the trailing empty lines"
+                );
+                Ok(())
+            }
+        }
+
+        #[test]
+        pub fn test_sections() {
+            // Given an input with a preamble and two named sections
+            let input = Input::from_lines(&[
+                "seeds: 79 14",
+                "",
+                "seed-to-soil map:",
+                "50 98 2",
+                "52 50 48",
+                "",
+                "soil-to-fertilizer map:",
+                "0 15 37",
+            ]);
+
+            // When sections is called with headers ending in "map:"
+            let actual = input.sections(|line| line.ends_with("map:"));
+
+            // Then it should return every section in order, keyed by its header
+            assert_eq!(
+                actual,
+                vec![
+                    ("", vec!["seeds: 79 14"]),
+                    ("seed-to-soil map:", vec!["50 98 2", "52 50 48"]),
+                    ("soil-to-fertilizer map:", vec!["0 15 37"]),
+                ],
+                "sections should return the preamble and both maps in order"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_write_file() -> Result<()> {
+        // Given a file path and content data
+        let file_contents: &str = "test data";
+        let (temp_dir, file_path) = setup_temp_file("test.txt")?;
+
+        // When write_file is called
+        write_file(&file_path, file_contents)?;
+
+        // Then it writes the data to the specified file path
+        let mut file = File::open(file_path)?;
+        let mut actual: String = String::new();
+        file.read_to_string(&mut actual)?;
+
+        assert_eq!(
+            actual, file_contents,
+            "write_file should write \"${file_contents}\" to specified file."
+        );
+        drop(temp_dir);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_write_file_p_creates_parent_directories() -> Result<()> {
+        // Given a path two directories deep inside directories that do not exist yet
+        let temp_dir: TempDir = tempdir()?;
+        let file_path = temp_dir.path().join("out").join("day01").join("answer.txt");
+
+        // When write_file_p is called
+        write_file_p(&file_path, "test data")?;
+
+        // Then it creates the directories and writes the data to the file
+        assert_eq!(
+            fs::read_to_string(&file_path)?,
+            "test data",
+            "write_file_p should write \"test data\" to a file in new directories"
+        );
+        drop(temp_dir);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_input_path() {
+        // Then the input should be looked up in the day's directory without an input directory
+        assert_eq!(
+            input_path_in(None, "day01"),
+            PathBuf::from("./day01/input"),
+            "input_path_in should return \"./day01/input\" without AOC_INPUT_DIR"
+        );
+
+        // And in the input directory when one is given
+        assert_eq!(
+            input_path_in(Some(Path::new("/fixtures")), "day01"),
+            PathBuf::from("/fixtures/day01/input"),
+            "input_path_in should return \"/fixtures/day01/input\" with AOC_INPUT_DIR"
+        );
+    }
+
+    #[test]
+    pub fn test_load_or_fetch_existing_file() -> Result<()> {
+        // Given an input file that is already present
+        let temp_dir: TempDir = tempdir()?;
+        let path = temp_dir.path().join("day01").join("input");
+        write_file_p(&path, "1abc2\npqr3stu8vwx\n")?;
+
+        // When load_or_fetch is called
+        let actual = Input::load_or_fetch(&path, 1)?;
+
+        // Then it should load the existing file
+        assert_eq!(
+            actual.lines(),
+            &["1abc2", "pqr3stu8vwx"],
+            "load_or_fetch should load the existing file"
+        );
+        // And it should never fetch the input again
+        let mut fetched = false;
+        Input::load_or_else(&path, || {
+            fetched = true;
+            Ok("changed".to_string())
+        })?;
+        assert!(!fetched, "load_or_else should not fetch an existing file");
+        drop(temp_dir);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_load_or_else_directory() -> Result<()> {
+        // Given a directory where the input file should be
+        let temp_dir: TempDir = tempdir()?;
+        let path = temp_dir.path().join("input");
+        fs::create_dir(&path)?;
+
+        // When load_or_else is called
+        let mut fetched = false;
+        let actual = Input::load_or_else(&path, || {
+            fetched = true;
+            Ok("1abc2\n".to_string())
+        });
+
+        // Then it should report the directory without fetching anything
+        assert_eq!(
+            actual.map_err(|error| error.to_string()).err(),
+            Some(format!("{} exists but is not a file", path.display())),
+            "load_or_else should report a directory at the input path"
+        );
+        assert!(!fetched, "load_or_else should not fetch over a directory");
+        drop(temp_dir);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_load_or_else_missing_file() -> Result<()> {
+        // Given a day directory without an input file
+        let temp_dir: TempDir = tempdir()?;
+        let path = temp_dir.path().join("day01").join("input");
+
+        // When the fetch fails or returns nothing
+        let failed = Input::load_or_else(&path, || Err(io::Error::other("offline")));
+        let empty = Input::load_or_else(&path, || Ok("\n".to_string()));
+
+        // Then it should return errors and leave no file behind
+        assert!(
+            failed.is_err() && empty.is_err() && !path.exists(),
+            "load_or_else should not save a failed or empty download"
+        );
+        // And a successful fetch should be saved and loaded
+        let actual = Input::load_or_else(&path, || Ok("1abc2\n".to_string()))?;
+        assert_eq!(
+            (actual.lines(), fs::read_to_string(&path)?),
+            (&vec!["1abc2".to_string()], "1abc2\n".to_string()),
+            "load_or_else should save the fetched input and load it"
+        );
+        let leftovers = fs::read_dir(path.parent().unwrap())?.count();
+        assert_eq!(leftovers, 1, "load_or_else should not leave a partial file");
+        drop(temp_dir);
+        Ok(())
+    }
+
+    mod test_for_day {
+        use crate::{find_existing, input_candidates, write_file_p};
+        use anyhow::Result;
+        use std::fs;
+        use tempfile::tempdir;
+
+        #[test]
+        pub fn first_candidate_wins() -> Result<()> {
+            // Given an input in the input directory, the workspace and the day's directory
+            let temp_dir = tempdir()?;
+            let (input_dir, base) = (temp_dir.path().join("inputs"), temp_dir.path());
+            for path in [
+                input_dir.join("day01").join("input"),
+                base.join("day01").join("input"),
+                base.join("input"),
+            ] {
+                write_file_p(path, "1abc2")?;
+            }
+
+            // When the input of the day is resolved
+            let candidates = input_candidates(Some(&input_dir), base, "day01");
+
+            // Then the input directory should take precedence
+            assert_eq!(
+                find_existing(&candidates)?,
+                &input_dir.join("day01").join("input"),
+                "for_day should prefer the input in AOC_INPUT_DIR"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn falls_through_to_later_candidate() -> Result<()> {
+            // Given only an input in the day's own directory
+            let temp_dir = tempdir()?;
+            let (input_dir, base) = (temp_dir.path().join("inputs"), temp_dir.path());
+            write_file_p(base.join("input"), "1abc2")?;
+
+            // When the input of the day is resolved
+            let candidates = input_candidates(Some(&input_dir), base, "day01");
+
+            // Then the missing inputs should be skipped and the last candidate used
+            assert_eq!(
+                find_existing(&candidates)?,
+                &base.join("input"),
+                "for_day should fall through to ./input"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn skips_directories() -> Result<()> {
+            // Given an input directory where the workspace input should be
+            let temp_dir = tempdir()?;
+            let base = temp_dir.path();
+            fs::create_dir_all(base.join("day01").join("input"))?;
+            write_file_p(base.join("input"), "1abc2")?;
+
+            // When the input of the day is resolved
+            let candidates = input_candidates(None, base, "day01");
+
+            // Then the directory should be skipped in favor of the next file
+            assert_eq!(
+                find_existing(&candidates)?,
+                &base.join("input"),
+                "for_day should skip a directory named input"
+            );
+            Ok(())
+        }
+
+        #[test]
+        pub fn no_candidate_exists() -> Result<()> {
+            // Given a directory without any input
+            let temp_dir = tempdir()?;
+            let base = temp_dir.path();
+
+            // When the input of the day is resolved
+            let candidates = input_candidates(None, base, "day01");
+            let error = find_existing(&candidates).unwrap_err();
+
+            // Then it should report every place it looked in
+            assert_eq!(
+                error.to_string(),
+                format!(
+                    "no input found, looked in {}, {}",
+                    base.join("day01").join("input").display(),
+                    base.join("input").display()
+                ),
+                "for_day should list the candidates when no input exists"
+            );
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn test_timed() {
+        // Given a function returning a value
+        let f = || vec![1, 2, 3];
+
+        // When it is run through timed
+        let actual = timed("test", f);
+
+        // Then the value should be passed through unchanged
+        assert_eq!(
+            actual,
+            vec![1, 2, 3],
+            "timed should return the value returned by the function"
+        );
+    }
+
     #[test]
     pub fn test_read_file() -> Result<()> {
         // Given a path to file containing data
@@ -0,0 +1,182 @@
+//! A memory-mapped, read-only view of an input file.
+//!
+//! `MappedInput` borrows its lines straight from the mapped file instead of
+//! copying them into owned strings, which saves a copy and one allocation per
+//! line on large inputs.
+
+use crate::Input;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Represents an input file mapped into memory, exposing its lines as borrowed slices.
+pub struct MappedInput {
+    mmap: Mmap,
+}
+
+impl MappedInput {
+    /// Maps a file into memory and checks that it is valid UTF-8.
+    ///
+    /// The file must not be modified while it is mapped, as is the case for puzzle
+    /// inputs that are only ever read.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the file, such as a `&str` or a `PathBuf`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `MappedInput`, or an `io::Error` if the file cannot be mapped or
+    /// is not valid UTF-8.
+    pub fn open(file_path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(file_path)?;
+        // SAFETY: the mapping is read-only, and puzzle inputs are not modified while a
+        // solution is running.
+        let mmap = unsafe { Mmap::map(&file)? };
+        std::str::from_utf8(&mmap)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Self { mmap })
+    }
+
+    /// Provides the full text of the mapped file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the contents of the file as a string slice.
+    pub fn text(&self) -> &str {
+        // SAFETY: the contents were validated as UTF-8 in `open` and cannot change
+        // through a read-only mapping.
+        unsafe { std::str::from_utf8_unchecked(&self.mmap) }
+    }
+
+    /// Iterates over the lines of the mapped file.
+    ///
+    /// The lines are normalized as in `Input::load`: lines are split on `\n`, a
+    /// trailing `\r` is removed from every line, a final line ending does not produce
+    /// an extra empty line, and a byte order mark at the start of the file is removed.
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator yielding each line as a slice borrowed from the mapping.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        normalized_slices(self.text())
+    }
+
+    /// Copies the lines of the mapped file into an owned `Input`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Input` instance with the same lines as `lines`.
+    pub fn to_input(&self) -> Input {
+        let lines: Vec<&str> = self.lines().collect();
+        Input::from_lines(&lines)
+    }
+}
+
+/// Splits text into lines the way `Input::load` does, borrowing every line from the text.
+fn normalized_slices(text: &str) -> impl Iterator<Item = &str> {
+    let body = text.strip_suffix('\n').unwrap_or(text);
+    (!text.is_empty())
+        .then(|| body.split('\n'))
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if index == 0 {
+                line.strip_prefix('\u{FEFF}').unwrap_or(line)
+            } else {
+                line
+            }
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    pub fn test_lines() -> anyhow::Result<()> {
+        // Given a file with a byte order mark and mixed line endings
+        let temp_dir = tempdir()?;
+        let file_path = temp_dir.path().join("input");
+        std::fs::write(&file_path, "\u{FEFF}Line 1\r\nLine 2\nLine 3\n")?;
+
+        // When the file is mapped
+        let mapped = MappedInput::open(&file_path)?;
+
+        // Then its lines should match the lines loaded by Input::load
+        assert_eq!(
+            mapped.lines().collect::<Vec<_>>(),
+            vec!["Line 1", "Line 2", "Line 3"],
+            "MappedInput should yield [\"Line 1\", \"Line 2\", \"Line 3\"]"
+        );
+        assert_eq!(
+            mapped.to_input().lines(),
+            Input::load(&file_path)?.lines(),
+            "to_input should return the same lines as Input::load"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_lines_match_load() -> anyhow::Result<()> {
+        // Given files with unusual line endings
+        let temp_dir = tempdir()?;
+        let file_path = temp_dir.path().join("input");
+        for contents in [
+            "a\rb\r",
+            "a\r\nb\r",
+            "a\r\nb",
+            "\n",
+            "a\n\n",
+            "\u{FEFF}\r\n",
+        ] {
+            std::fs::write(&file_path, contents)?;
+
+            // Then the mapped lines should match the lines loaded by Input::load
+            assert_eq!(
+                MappedInput::open(&file_path)?.lines().collect::<Vec<_>>(),
+                Input::load(&file_path)?.lines().clone(),
+                "MappedInput should split {contents:?} like Input::load"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_empty_file() -> anyhow::Result<()> {
+        // Given an empty file
+        let temp_dir = tempdir()?;
+        let file_path = temp_dir.path().join("input");
+        std::fs::write(&file_path, "")?;
+
+        // Then mapping it should yield no lines
+        assert_eq!(
+            MappedInput::open(&file_path)?.lines().count(),
+            0,
+            "MappedInput should yield no lines for an empty file"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_invalid_utf8() -> anyhow::Result<()> {
+        // Given a file containing an invalid UTF-8 byte
+        let temp_dir = tempdir()?;
+        let file_path = temp_dir.path().join("input");
+        std::fs::write(&file_path, b"ab\xFFcd")?;
+
+        // Then mapping it should return an invalid data error
+        assert_eq!(
+            MappedInput::open(&file_path)
+                .err()
+                .map(|error| error.kind()),
+            Some(io::ErrorKind::InvalidData),
+            "MappedInput::open should return InvalidData for invalid UTF-8"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+/// Sums the values in a rectangular region of a numeric grid.
+///
+/// The region is clamped to the bounds of the grid, so parts of it that lie
+/// outside the grid contribute nothing.
+///
+/// # Arguments
+///
+/// * `grid` - The rows of the numeric grid.
+/// * `top` - The index of the first row of the region.
+/// * `left` - The index of the first column of the region.
+/// * `h` - The number of rows in the region.
+/// * `w` - The number of columns in the region.
+///
+/// # Returns
+///
+/// Returns the sum of the values within the region.
+pub fn region_sum(grid: &[Vec<i64>], top: usize, left: usize, h: usize, w: usize) -> i64 {
+    grid.iter()
+        .skip(top)
+        .take(h)
+        .map(|row| row.iter().skip(left).take(w).sum::<i64>())
+        .sum()
+}
+
+/// Computes the HASH value of a string, as defined by the day 15 puzzle.
+///
+/// Starting from 0, each byte is added to the current value, which is then
+/// multiplied by 17 and reduced modulo 256.
+///
+/// # Arguments
+///
+/// * `s` - A string slice of ASCII characters to hash.
+///
+/// # Returns
+///
+/// Returns the HASH value as a `u8`.
+pub fn aoc_hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0u8, |hash, byte| hash.wrapping_add(byte).wrapping_mul(17))
+}
+
+/// Fits a quadratic through three samples and returns a function evaluating it.
+///
+/// The quadratic is found by Lagrange interpolation over the first three
+/// samples. This suits values that grow quadratically with a period, such as
+/// the number of cells reached in an infinitely tiled grid: sampling after
+/// one, two and three periods is enough to predict any later period.
+///
+/// # Arguments
+///
+/// * `samples` - The `(x, y)` points to fit, of which the first three are used.
+///
+/// # Returns
+///
+/// Returns a function that evaluates the quadratic at a given `x`. The result is
+/// truncated towards zero if the quadratic does not take an integer value there.
+///
+/// # Panics
+///
+/// Panics if fewer than three samples are provided or if two of them share the
+/// same `x`.
+pub fn extrapolate_quadratic(samples: &[(i64, i64)]) -> impl Fn(i64) -> i64 {
+    assert!(
+        samples.len() >= 3,
+        "extrapolate_quadratic needs at least three samples"
+    );
+    let points: [(i128, i128); 3] =
+        std::array::from_fn(|i| (samples[i].0 as i128, samples[i].1 as i128));
+    let denominators: [i128; 3] = std::array::from_fn(|i| {
+        (0..3)
+            .filter(|&j| j != i)
+            .map(|j| points[i].0 - points[j].0)
+            .product()
+    });
+    assert!(
+        denominators.iter().all(|&d| d != 0),
+        "extrapolate_quadratic needs samples with distinct x values"
+    );
+    let common: i128 = denominators.iter().product();
+
+    move |x| {
+        let x = x as i128;
+        let numerator: i128 = (0..3)
+            .map(|i| {
+                let basis: i128 = (0..3)
+                    .filter(|&j| j != i)
+                    .map(|j| x - points[j].0)
+                    .product();
+                points[i].1 * basis * (common / denominators[i])
+            })
+            .sum();
+        (numerator / common) as i64
+    }
+}
+
+/// Precomputed prefix sums of a numeric grid for answering repeated region sums quickly.
+pub struct PrefixSums {
+    width: usize,
+    height: usize,
+    sums: Vec<i64>,
+}
+
+impl PrefixSums {
+    /// Precomputes the prefix sums of a numeric grid.
+    ///
+    /// Rows shorter than the longest row are treated as if padded with zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The rows of the numeric grid.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PrefixSums` instance for the grid.
+    pub fn new(grid: &[Vec<i64>]) -> Self {
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut sums = vec![0; (width + 1) * (height + 1)];
+        for (y, row) in grid.iter().enumerate() {
+            for x in 0..width {
+                let value = row.get(x).copied().unwrap_or(0);
+                sums[(y + 1) * (width + 1) + x + 1] =
+                    value + sums[y * (width + 1) + x + 1] + sums[(y + 1) * (width + 1) + x]
+                        - sums[y * (width + 1) + x];
+            }
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    /// Sums the values in a rectangular region of the grid in constant time.
+    ///
+    /// The region is clamped to the bounds of the grid, just like `region_sum`.
+    ///
+    /// # Arguments
+    ///
+    /// * `top` - The index of the first row of the region.
+    /// * `left` - The index of the first column of the region.
+    /// * `h` - The number of rows in the region.
+    /// * `w` - The number of columns in the region.
+    ///
+    /// # Returns
+    ///
+    /// Returns the sum of the values within the region.
+    pub fn region_sum(&self, top: usize, left: usize, h: usize, w: usize) -> i64 {
+        let (top, bottom) = (top.min(self.height), top.saturating_add(h).min(self.height));
+        let (left, right) = (left.min(self.width), left.saturating_add(w).min(self.width));
+        let at = |x: usize, y: usize| self.sums[y * (self.width + 1) + x];
+        at(right, bottom) - at(left, bottom) - at(right, top) + at(left, top)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Helper function that creates a 3x3 numeric grid
+    fn setup_grid() -> Vec<Vec<i64>> {
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]
+    }
+
+    mod test_region_sum {
+        use super::setup_grid;
+        use crate::region_sum;
+
+        #[test]
+        pub fn inner_region() {
+            // Then it should sum the values of a region within the grid
+            assert_eq!(
+                region_sum(&setup_grid(), 1, 1, 2, 2),
+                28,
+                "region_sum should return 28 for the bottom-right 2x2 region"
+            )
+        }
+
+        #[test]
+        pub fn clamped_region() {
+            // Then it should ignore the parts of a region outside the grid
+            assert_eq!(
+                region_sum(&setup_grid(), 2, 1, 5, 5),
+                17,
+                "region_sum should return 17 for a region overflowing the bottom row"
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_prefix_sums_region_sum() {
+        // Given the prefix sums of a grid
+        let grid = setup_grid();
+        let prefix_sums = PrefixSums::new(&grid);
+
+        // Then every region sum should match the direct computation
+        for (top, left, h, w) in [(0, 0, 3, 3), (1, 1, 2, 2), (2, 1, 5, 5), (0, 2, 1, 1)] {
+            assert_eq!(
+                prefix_sums.region_sum(top, left, h, w),
+                region_sum(&grid, top, left, h, w),
+                "PrefixSums.region_sum should match region_sum for \
+                   ({top}, {left}, {h}, {w})"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_extrapolate_quadratic() {
+        // Given three samples of y = x^2
+        let samples = [(1, 1), (2, 4), (4, 16)];
+
+        // When the quadratic is fitted and evaluated at a fourth point
+        let quadratic = extrapolate_quadratic(&samples);
+
+        // Then it should return the square of that point
+        assert_eq!(
+            quadratic(10),
+            100,
+            "extrapolate_quadratic should evaluate y = x^2 to 100 at x = 10"
+        );
+    }
+
+    #[test]
+    pub fn test_aoc_hash() {
+        // Then it should return the HASH values of the puzzle examples
+        assert_eq!(
+            aoc_hash("HASH"),
+            52,
+            "aoc_hash should return 52 for \"HASH\""
+        );
+        assert_eq!(
+            aoc_hash("rn=1"),
+            30,
+            "aoc_hash should return 30 for \"rn=1\""
+        );
+    }
+}
@@ -0,0 +1,81 @@
+/// Which whitespace to remove from each line while loading input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Trim {
+    /// Keeps every line as it is.
+    #[default]
+    None,
+    /// Removes leading whitespace.
+    Start,
+    /// Removes trailing whitespace.
+    End,
+    /// Removes leading and trailing whitespace.
+    Both,
+}
+
+impl Trim {
+    /// Applies the trimming to a single line.
+    pub(crate) fn apply(self, line: String) -> String {
+        match self {
+            Trim::None => line,
+            Trim::Start => line.trim_start().to_string(),
+            Trim::End => line.trim_end().to_string(),
+            Trim::Both => line.trim().to_string(),
+        }
+    }
+}
+
+/// What to do with empty lines while loading input.
+///
+/// Lines are checked after trimming, so with trimming enabled a line of only
+/// whitespace counts as empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyLines {
+    /// Keeps every empty line.
+    #[default]
+    Keep,
+    /// Removes every empty line.
+    Skip,
+    /// Removes the empty lines at the end of the input, keeping the others.
+    SkipTrailing,
+}
+
+/// Options controlling how `Input::load_with` normalizes the lines of a file.
+///
+/// The default options behave exactly like `Input::load`: a trailing `\r` is
+/// removed from every line, nothing is trimmed and empty lines are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadOptions {
+    pub(crate) strip_cr: bool,
+    pub(crate) trim: Trim,
+    pub(crate) empty_lines: EmptyLines,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            strip_cr: true,
+            trim: Trim::None,
+            empty_lines: EmptyLines::Keep,
+        }
+    }
+}
+
+impl LoadOptions {
+    /// Sets whether a trailing `\r` is removed from every line, as with CRLF line endings.
+    pub fn strip_cr(mut self, strip_cr: bool) -> Self {
+        self.strip_cr = strip_cr;
+        self
+    }
+
+    /// Sets which whitespace is removed from every line.
+    pub fn trim(mut self, trim: Trim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets what to do with empty lines.
+    pub fn empty_lines(mut self, empty_lines: EmptyLines) -> Self {
+        self.empty_lines = empty_lines;
+        self
+    }
+}
@@ -0,0 +1,357 @@
+use std::str::FromStr;
+
+/// Extracts every signed integer from a string, in the order they appear.
+///
+/// A run of consecutive ASCII digits forms a number. A `-` immediately
+/// followed by a digit is treated as the sign of that number. Line breaks are
+/// treated like any other separator, so the whole text can be scanned at once.
+/// Numbers that do not fit in an `i64` are skipped.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that may contain integers among any other characters.
+///
+/// # Returns
+///
+/// Returns a `Vec<i64>` containing the integers found in the text.
+pub fn extract_ints(text: &str) -> Vec<i64> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let is_sign =
+            bytes[index] == b'-' && bytes.get(index + 1).is_some_and(|b| b.is_ascii_digit());
+        if bytes[index].is_ascii_digit() || is_sign {
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            if let Ok(number) = text[start..index].parse::<i64>() {
+                numbers.push(number);
+            }
+        } else {
+            index += 1;
+        }
+    }
+    numbers
+}
+
+/// Extracts every unsigned integer from a string, in the order they appear.
+///
+/// A run of consecutive ASCII digits forms a number. Signs are ignored, so
+/// `-5` yields `5`. Numbers that do not fit in a `u64` are skipped.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that may contain integers among any other characters.
+///
+/// # Returns
+///
+/// Returns a `Vec<u64>` containing the integers found in the text.
+pub fn extract_uints(text: &str) -> Vec<u64> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter(|digits| !digits.is_empty())
+        .filter_map(|digits| digits.parse().ok())
+        .collect()
+}
+
+/// Splits a string on a delimiter and parses every piece into a typed value.
+///
+/// Whitespace around each piece is trimmed before parsing, so `"1, 2,3"` split on
+/// `","` yields `[1, 2, 3]`. Empty pieces are passed to the parser as well, which
+/// keeps a missing value from going unnoticed.
+///
+/// # Arguments
+///
+/// * `text` - A string slice containing the delimited values.
+/// * `delimiter` - The string separating the values, such as `","` or `"; "`.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the parsed values in order, or the error of the
+/// first piece that could not be parsed.
+pub fn split_on<T: FromStr>(text: &str, delimiter: &str) -> Result<Vec<T>, T::Err> {
+    text.split(delimiter)
+        .map(|piece| piece.trim().parse())
+        .collect()
+}
+
+/// Separates the digit characters of a line from the remaining characters.
+///
+/// # Arguments
+///
+/// * `line` - A string slice that may contain any characters.
+///
+/// # Returns
+///
+/// Returns a tuple `(digits, non_digits)` where both strings keep the characters
+/// in their original order.
+pub fn split_digits(line: &str) -> (String, String) {
+    line.chars().partition(|c| c.is_ascii_digit())
+}
+
+/// Finds the longest prefix shared by all lines.
+///
+/// The prefix is compared character by character, so it never splits a
+/// multi-byte character.
+///
+/// # Arguments
+///
+/// * `lines` - A slice of lines to compare.
+///
+/// # Returns
+///
+/// Returns the shared prefix as a `String`, which is empty if the lines share no
+/// prefix or if the slice is empty.
+pub fn common_prefix<T: AsRef<str>>(lines: &[T]) -> String {
+    let Some((first, rest)) = lines.split_first() else {
+        return String::new();
+    };
+    let mut prefix = first.as_ref();
+    for line in rest {
+        let length: usize = prefix
+            .chars()
+            .zip(line.as_ref().chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        prefix = &prefix[..length];
+    }
+    prefix.to_string()
+}
+
+/// The cubes drawn in one set of a game, as `(count, color)` pairs.
+pub type CubeSet = Vec<(u32, String)>;
+
+/// Parses a line describing the sets of cubes drawn in a game.
+///
+/// The line has the form `Game <id>: <count> <color>, ...; <count> <color>, ...`,
+/// where each semicolon-separated part is one set of drawn cubes.
+///
+/// # Arguments
+///
+/// * `line` - A string slice containing a single game.
+///
+/// # Returns
+///
+/// An `Option` containing the game id and, for each set, its `(count, color)` pairs,
+/// or `None` if the line is malformed.
+pub fn parse_game(line: &str) -> Option<(u32, Vec<CubeSet>)> {
+    let (game, sets) = line.split_once(':')?;
+    let id = game.trim().strip_prefix("Game")?.trim().parse().ok()?;
+    let sets = sets
+        .split(';')
+        .map(|set| {
+            set.split(',')
+                .map(|cubes| {
+                    let (count, color) = cubes.trim().split_once(' ')?;
+                    Some((count.parse().ok()?, color.trim().to_string()))
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((id, sets))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_extract_ints() {
+        // Given a multi-line blob containing positive and negative integers
+        let text = "seeds: 79 -14\n55, -13\nend";
+
+        // When extract_ints is called
+        let actual = extract_ints(text);
+
+        // Then it should return every integer in order, regardless of line breaks
+        assert_eq!(
+            actual,
+            vec![79, -14, 55, -13],
+            "extract_ints should return [79, -14, 55, -13] for the provided text"
+        );
+    }
+
+    mod test_extract_ints {
+        use crate::{extract_ints, extract_uints};
+
+        #[test]
+        pub fn attached_sign() {
+            // Then it should treat a '-' directly before a number as its sign
+            assert_eq!(
+                extract_ints("x=-5, y=12"),
+                vec![-5, 12],
+                "extract_ints should return [-5, 12] for \"x=-5, y=12\""
+            )
+        }
+
+        #[test]
+        pub fn lone_dash() {
+            // Then it should not treat a '-' between words as a sign
+            assert_eq!(
+                extract_ints("a - b 3 well-known 4"),
+                vec![3, 4],
+                "extract_ints should return [3, 4] for \"a - b 3 well-known 4\""
+            )
+        }
+
+        #[test]
+        pub fn separated_by_letters() {
+            // Then it should split numbers separated only by letters
+            assert_eq!(
+                extract_ints("12ab34c5"),
+                vec![12, 34, 5],
+                "extract_ints should return [12, 34, 5] for \"12ab34c5\""
+            )
+        }
+
+        #[test]
+        pub fn start_and_end() {
+            // Then it should find numbers at the very start and end of the string
+            assert_eq!(
+                extract_ints("-7 apples and 8"),
+                vec![-7, 8],
+                "extract_ints should return [-7, 8] for \"-7 apples and 8\""
+            )
+        }
+
+        #[test]
+        pub fn unsigned_ignores_signs() {
+            // Then it should ignore signs and return the digits as unsigned numbers
+            assert_eq!(
+                extract_uints("-3 x=-5,y=12ab7"),
+                vec![3, 5, 12, 7],
+                "extract_uints should return [3, 5, 12, 7] for \"-3 x=-5,y=12ab7\""
+            )
+        }
+    }
+
+    mod test_split_on {
+        use crate::split_on;
+
+        #[test]
+        pub fn numbers() {
+            // Then it should parse every trimmed piece
+            assert_eq!(
+                split_on::<u32>("3, 4,5 ", ","),
+                Ok(vec![3, 4, 5]),
+                "split_on should return [3, 4, 5] for \"3, 4,5 \""
+            )
+        }
+
+        #[test]
+        pub fn records() {
+            // Then it should split on delimiters longer than one character
+            assert_eq!(
+                split_on::<String>("3 blue, 4 red; 2 green", ";"),
+                Ok(vec!["3 blue, 4 red".to_string(), "2 green".to_string()]),
+                "split_on should return both sets of \"3 blue, 4 red; 2 green\""
+            )
+        }
+
+        #[test]
+        pub fn invalid_piece() {
+            // Then it should return an error for a piece that can not be parsed
+            assert!(
+                split_on::<u32>("1,,3", ",").is_err(),
+                "split_on should return an error for the empty piece of \"1,,3\""
+            )
+        }
+    }
+
+    #[test]
+    pub fn test_split_digits() {
+        // Given a line of interleaved letters and digits
+        let line = "a1b2c";
+
+        // When split_digits is called
+        let actual = split_digits(line);
+
+        // Then it should return the digits and the remaining characters separately
+        assert_eq!(
+            actual,
+            ("12".to_string(), "abc".to_string()),
+            "split_digits should return (\"12\", \"abc\") for an input of \"a1b2c\""
+        );
+    }
+
+    mod test_common_prefix {
+        use crate::common_prefix;
+
+        #[test]
+        pub fn shared_prefix() {
+            // Then it should return the prefix shared by all lines
+            assert_eq!(
+                common_prefix(&["Card 1: 41", "Card 2: 13", "Card 3: 1"]),
+                "Card ",
+                "common_prefix should return \"Card \" for lines starting with cards"
+            )
+        }
+
+        #[test]
+        pub fn no_shared_prefix() {
+            // Then it should return an empty string when the lines share nothing
+            assert_eq!(
+                common_prefix(&["abc", "xyz"]),
+                "",
+                "common_prefix should return an empty string for [\"abc\", \"xyz\"]"
+            )
+        }
+
+        #[test]
+        pub fn empty_slice() {
+            // Then it should return an empty string for an empty slice
+            assert_eq!(
+                common_prefix::<&str>(&[]),
+                "",
+                "common_prefix should return an empty string for an empty slice"
+            )
+        }
+    }
+
+    mod test_parse_game {
+        use crate::{parse_game, CubeSet};
+
+        #[test]
+        pub fn sample_game() {
+            // Given the first game of the sample input
+            let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+
+            // When parse_game is called
+            let actual = parse_game(line);
+
+            // Then it should return the game id and the cubes of every set
+            let set = |cubes: &[(u32, &str)]| -> CubeSet {
+                cubes
+                    .iter()
+                    .map(|&(count, color)| (count, color.to_string()))
+                    .collect()
+            };
+            assert_eq!(
+                actual,
+                Some((
+                    1,
+                    vec![
+                        set(&[(3, "blue"), (4, "red")]),
+                        set(&[(1, "red"), (2, "green"), (6, "blue")]),
+                        set(&[(2, "green")]),
+                    ]
+                )),
+                "parse_game should return the id and three sets for the sample game"
+            );
+        }
+
+        #[test]
+        pub fn malformed_line() {
+            // Then it should return None for a line with an invalid count
+            assert_eq!(
+                parse_game("Game 2: three blue"),
+                None,
+                "parse_game should return None for \"Game 2: three blue\""
+            )
+        }
+    }
+}
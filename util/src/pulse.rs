@@ -0,0 +1,195 @@
+//! Simulation of pulse-propagating module networks.
+//!
+//! A network is described by lines such as `broadcaster -> a, b`, `%a -> b`
+//! and `&b -> c`, where `%` marks a flip-flop module and `&` marks a conjunction
+//! module. Modules that are only ever referenced as outputs receive pulses but
+//! never send any.
+
+use crate::Input;
+use anyhow::{bail, Result};
+use std::collections::{HashMap, VecDeque};
+
+/// The name of the module that receives the pulse from the button.
+const BROADCASTER: &str = "broadcaster";
+
+/// The behavior of a module when it receives a pulse.
+enum Kind {
+    /// Sends every received pulse to all of its outputs.
+    Broadcaster,
+    /// Ignores high pulses and toggles on low pulses, sending high when turned on.
+    FlipFlop { on: bool },
+    /// Remembers the last pulse from each input and sends low only when all were high.
+    Conjunction { memory: HashMap<String, bool> },
+}
+
+/// A single module of the network together with the modules it sends pulses to.
+struct Module {
+    kind: Kind,
+    outputs: Vec<String>,
+}
+
+/// Represents a network of modules that propagate low and high pulses.
+pub struct Network {
+    modules: HashMap<String, Module>,
+}
+
+impl Network {
+    /// Parses a network from module definitions, one per line.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - An `Input` instance containing lines like `%a -> b, c`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed `Network`, or an error if a line is not a valid module definition.
+    pub fn from_input(input: &Input) -> Result<Self> {
+        let mut modules = HashMap::new();
+        for line in input.lines().iter().filter(|line| !line.trim().is_empty()) {
+            let Some((name, outputs)) = line.split_once("->") else {
+                bail!("invalid module definition: {line}");
+            };
+            let outputs: Vec<String> = outputs
+                .split(',')
+                .map(|output| output.trim().to_string())
+                .filter(|output| !output.is_empty())
+                .collect();
+            let name = name.trim();
+            let (name, kind) = if let Some(name) = name.strip_prefix('%') {
+                (name, Kind::FlipFlop { on: false })
+            } else if let Some(name) = name.strip_prefix('&') {
+                (
+                    name,
+                    Kind::Conjunction {
+                        memory: HashMap::new(),
+                    },
+                )
+            } else if name == BROADCASTER {
+                (name, Kind::Broadcaster)
+            } else {
+                bail!("unknown module type: {name}");
+            };
+            modules.insert(name.to_string(), Module { kind, outputs });
+        }
+
+        let connections: Vec<(String, String)> = modules
+            .iter()
+            .flat_map(|(name, module)| {
+                module
+                    .outputs
+                    .iter()
+                    .map(move |output| (name.clone(), output.clone()))
+            })
+            .collect();
+        for (source, target) in connections {
+            if let Some(Module {
+                kind: Kind::Conjunction { memory },
+                ..
+            }) = modules.get_mut(&target)
+            {
+                memory.insert(source, false);
+            }
+        }
+        Ok(Self { modules })
+    }
+
+    /// Presses the button once, sending a low pulse to the broadcaster, and
+    /// processes pulses until the network settles.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple `(low, high)` with the number of low and high pulses sent
+    /// during this press, including the pulse from the button.
+    pub fn press_button(&mut self) -> (u64, u64) {
+        let mut counts = (0, 0);
+        let mut queue = VecDeque::from([(String::new(), BROADCASTER.to_string(), false)]);
+
+        while let Some((source, target, high)) = queue.pop_front() {
+            if high {
+                counts.1 += 1;
+            } else {
+                counts.0 += 1;
+            }
+            let Some(module) = self.modules.get_mut(&target) else {
+                continue;
+            };
+            let output = match &mut module.kind {
+                Kind::Broadcaster => Some(high),
+                Kind::FlipFlop { on } => {
+                    if high {
+                        None
+                    } else {
+                        *on = !*on;
+                        Some(*on)
+                    }
+                }
+                Kind::Conjunction { memory } => {
+                    memory.insert(source, high);
+                    Some(!memory.values().all(|&remembered| remembered))
+                }
+            };
+            if let Some(pulse) = output {
+                for next in &module.outputs {
+                    queue.push_back((target.clone(), next.clone(), pulse));
+                }
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_press_button_two_modules() -> Result<()> {
+        // Given a broadcaster connected to a single flip-flop
+        let input = Input::from_lines(&["broadcaster -> a", "%a -> output"]);
+        let mut network = Network::from_input(&input)?;
+
+        // When the button is pressed once
+        let actual = network.press_button();
+
+        // Then two low pulses and one high pulse should be sent
+        assert_eq!(
+            actual,
+            (2, 1),
+            "press_button should send 2 low and 1 high pulse for the provided network"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_press_button_with_conjunction() -> Result<()> {
+        // Given a network of flip-flops looping back through a conjunction
+        let input = Input::from_lines(&[
+            "broadcaster -> a, b, c",
+            "%a -> b",
+            "%b -> c",
+            "%c -> inv",
+            "&inv -> a",
+        ]);
+        let mut network = Network::from_input(&input)?;
+
+        // When the button is pressed once
+        let actual = network.press_button();
+
+        // Then eight low pulses and four high pulses should be sent
+        assert_eq!(
+            actual,
+            (8, 4),
+            "press_button should send 8 low and 4 high pulses for the provided network"
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_from_input_invalid_line() {
+        // Then it should return an error for a line without an arrow
+        assert!(
+            Network::from_input(&Input::from_lines(&["%a b"])).is_err(),
+            "from_input should return an error for \"%a b\""
+        );
+    }
+}